@@ -0,0 +1,20 @@
+//! Detects whether the crate is being built by a nightly toolchain.
+//! 
+//! Author --- DMorgan  
+//! Last Moddified --- 2026-10-19
+
+use std::{env, process::Command,};
+
+fn main() {
+  println!("cargo:rerun-if-env-changed=RUSTC",);
+  println!("cargo:rustc-check-cfg=cfg(nightly)",);
+
+  let rustc = env::var_os("RUSTC",).unwrap_or_else(|| "rustc".into(),);
+  let version = Command::new(rustc,).arg("-V",).output().ok()
+    .and_then(|output| String::from_utf8(output.stdout,).ok(),)
+    .unwrap_or_default();
+
+  if version.contains("nightly",) || version.contains("-dev",) {
+    println!("cargo:rustc-cfg=nightly",);
+  }
+}
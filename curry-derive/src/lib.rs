@@ -0,0 +1,389 @@
+//! Procedural macros for implementing the `Fn*` traits, re-exported by `curry`.
+//! 
+//! Author --- DMorgan  
+//! Last Moddified --- 2026-10-19
+
+#![deny(missing_docs,)]
+#![cfg_attr(nightly, doc(test(attr(feature(fn_traits, unboxed_closures,),),),),)]
+
+use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::{quote, quote_spanned, format_ident,};
+use syn::{
+  parse_macro_input, parse_quote, spanned::Spanned, Error, FnArg, Generics, ImplItem, ImplItemFn,
+  ItemImpl, Ident, ReturnType, Type,
+};
+
+/// Implements the `Fn*` traits for a type from the methods of an inherent `impl` block which
+/// are marked `#[call]`.
+/// 
+/// The receiver of a `#[call]` method decides which traits are implemented: `&self` implements
+/// `Fn`, `FnMut` and `FnOnce`; `&mut self` implements `FnMut` and `FnOnce`; `self` implements
+/// `FnOnce`. Several `#[call]` methods can be used to overload the type.
+/// 
+/// The methods are left in place, passing `call` as in `#[fn_object(call)]` also generates an
+/// inherent `call` method forwarding to the marked method so the type can be called without the
+/// `Fn*` traits.
+/// 
+/// The generated impls require a nightly toolchain.
+/// 
+#[cfg_attr(nightly, doc = "```rust",)]
+#[cfg_attr(not(nightly,), doc = "```ignore",)]
+/// use curry::*;
+/// 
+/// struct Scale { factor: i32, }
+/// 
+/// #[fn_object(call)]
+/// impl Scale {
+///   #[call]
+///   fn scale(&self, x: i32) -> i32 { x * self.factor }
+/// }
+/// 
+/// struct Counter(u32);
+/// 
+/// #[fn_object]
+/// impl Counter {
+///   #[call]
+///   fn step(&mut self) -> u32 { self.0 += 1; self.0 }
+///   #[call]
+///   fn add<T: Into<u32>>(&mut self, x: T) -> u32 { self.0 += x.into(); self.0 }
+/// }
+/// 
+/// let scale = Scale { factor: 3, };
+/// assert_eq!(scale(2), 6);
+/// assert_eq!(scale.call(2), 6);
+/// assert_eq!(Curry2(|a, b| a + b)(1)(scale(4)), 13);
+/// 
+/// let mut counter = Counter(0);
+/// assert_eq!(counter(), 1);
+/// assert_eq!(counter(2u8), 3);
+/// ```
+#[proc_macro_attribute]
+pub fn fn_object(attr: TokenStream, item: TokenStream,) -> TokenStream {
+  let attr = TokenStream2::from(attr,);
+  let item = parse_macro_input!(item as ItemImpl);
+
+  match expand(attr, item,) {
+    Ok(tokens,) => tokens.into(),
+    Err(e,) => e.to_compile_error().into(),
+  }
+}
+
+/// Each error reported by `fn_object` is spanned to the offending tokens.
+/// 
+/// ```compile_fail
+/// use curry::*;
+/// 
+/// struct Unit;
+/// 
+/// #[fn_object]
+/// impl Clone for Unit { fn clone(&self) -> Self { Unit } }
+/// ```
+/// 
+/// ```compile_fail
+/// use curry::*;
+/// 
+/// struct Unit;
+/// 
+/// #[fn_object]
+/// impl Unit { fn unit(&self) {} }
+/// ```
+/// 
+/// ```compile_fail
+/// use curry::*;
+/// 
+/// struct Unit;
+/// 
+/// #[fn_object(call)]
+/// impl Unit {
+///   #[call]
+///   fn unit(&self) {}
+///   #[call]
+///   fn id(&self, x: i32) -> i32 { x }
+/// }
+/// ```
+/// 
+/// ```compile_fail
+/// use curry::*;
+/// 
+/// struct Unit;
+/// 
+/// #[fn_object(invoke)]
+/// impl Unit { #[call] fn unit(&self) {} }
+/// ```
+/// 
+/// ```compile_fail
+/// use curry::*;
+/// 
+/// struct Unit;
+/// 
+/// #[fn_object]
+/// impl Unit { #[call] async fn unit(&self) {} }
+/// ```
+/// 
+/// ```compile_fail
+/// use curry::*;
+/// 
+/// struct Unit;
+/// 
+/// #[fn_object]
+/// impl Unit { #[call] unsafe fn unit(&self) {} }
+/// ```
+/// 
+/// ```compile_fail
+/// use curry::*;
+/// 
+/// struct Unit;
+/// 
+/// #[fn_object]
+/// impl Unit { #[call] fn unit(&self, args: ...) {} }
+/// ```
+/// 
+/// ```compile_fail
+/// use curry::*;
+/// 
+/// struct Unit;
+/// 
+/// #[fn_object]
+/// impl Unit { #[call] fn unit(self: Box<Self>) {} }
+/// ```
+/// 
+/// ```compile_fail
+/// use curry::*;
+/// 
+/// struct Unit;
+/// 
+/// #[fn_object]
+/// impl Unit { #[call] fn unit(x: i32) {} }
+/// ```
+/// 
+/// ```compile_fail
+/// use curry::*;
+/// 
+/// struct Unit;
+/// 
+/// #[fn_object]
+/// impl Unit { #[call] fn unit(&self, x: impl Into<i32>) {} }
+/// ```
+/// 
+/// ```compile_fail
+/// use curry::*;
+/// 
+/// struct Unit;
+/// 
+/// #[fn_object]
+/// impl Unit { #[call] fn unit(&self) -> impl Clone { 1 } }
+/// ```
+#[cfg(doctest,)]
+struct Errors;
+
+/// The receiver of a `#[call]` method.
+#[derive(Clone, Copy, PartialEq, Eq,)]
+enum Receiver {
+  /// `&self`
+  Ref,
+  /// `&mut self`
+  Mut,
+  /// `self`
+  Value,
+}
+
+/// A `#[call]` method.
+struct CallFn {
+  /// The name of the method.
+  name: Ident,
+  /// The receiver of the method.
+  receiver: Receiver,
+  /// The generics of the method.
+  generics: Generics,
+  /// The types of the arguments.
+  arg_tps: Vec<Type>,
+  /// The return type.
+  ret: Type,
+}
+
+fn expand(attr: TokenStream2, mut item: ItemImpl,) -> syn::Result<TokenStream2> {
+  let inherent_call = parse_attr(attr,)?;
+
+  if let Some((_, path, _,),) = &item.trait_ {
+    return Err(Error::new(path.span(), "`fn_object` must be used on an inherent `impl` block",))
+  }
+
+  let mut call_fns = Vec::new();
+  for impl_item in &mut item.items {
+    if let ImplItem::Fn(method,) = impl_item {
+      if take_call_attr(method,) { call_fns.push(call_fn(method,)?,) }
+    }
+  }
+
+  if call_fns.is_empty() {
+    return Err(Error::new(item.self_ty.span(), "expected at least one method marked `#[call]`",))
+  }
+
+  let mut tokens = quote! { #item };
+  for call_fn in &call_fns { tokens.extend(impl_fns(&item, call_fn,),) }
+
+  if inherent_call {
+    if call_fns.len() > 1 {
+      return Err(Error::new(item.self_ty.span(), "`fn_object(call)` requires exactly one `#[call]` method",))
+    }
+
+    tokens.extend(impl_inherent_call(&item, &call_fns[0],),)
+  }
+
+  Ok(tokens)
+}
+
+/// Parses the arguments to `fn_object` returning whether an inherent `call` method is requested.
+fn parse_attr(attr: TokenStream2,) -> syn::Result<bool> {
+  if attr.is_empty() { return Ok(false) }
+
+  let ident = syn::parse2::<Ident>(attr.clone(),)
+    .map_err(|_| Error::new(attr.span(), "expected `call`",),)?;
+  if ident == "call" { Ok(true) }
+  else { Err(Error::new(ident.span(), "expected `call`",)) }
+}
+
+/// Removes the `#[call]` attribute from `method` returning whether it was present.
+fn take_call_attr(method: &mut ImplItemFn,) -> bool {
+  let len = method.attrs.len();
+
+  method.attrs.retain(|attr| !attr.path().is_ident("call",),);
+  method.attrs.len() != len
+}
+
+fn call_fn(method: &ImplItemFn,) -> syn::Result<CallFn> {
+  let sig = &method.sig;
+
+  if let Some(asyncness,) = &sig.asyncness {
+    return Err(Error::new(asyncness.span(), "`#[call]` methods cannot be `async`",))
+  }
+  if let Some(unsafety,) = &sig.unsafety {
+    return Err(Error::new(unsafety.span(), "`#[call]` methods cannot be `unsafe`",))
+  }
+  if let Some(variadic,) = &sig.variadic {
+    return Err(Error::new(variadic.span(), "`#[call]` methods cannot be variadic",))
+  }
+
+  let mut inputs = sig.inputs.iter();
+  let receiver = match inputs.next() {
+    Some(FnArg::Receiver(receiver,),) => {
+      if receiver.colon_token.is_some() {
+        return Err(Error::new(receiver.span(), "`#[call]` methods must take `self`, `&self` or `&mut self`",))
+      }
+
+      match (&receiver.reference, &receiver.mutability,) {
+        (Some(_), None,) => Receiver::Ref,
+        (Some(_), Some(_),) => Receiver::Mut,
+        (None, _,) => Receiver::Value,
+      }
+    },
+    _ => return Err(Error::new(sig.ident.span(), "`#[call]` methods must take `self`, `&self` or `&mut self`",)),
+  };
+
+  let mut arg_tps = Vec::new();
+  for input in inputs {
+    if let FnArg::Typed(arg,) = input {
+      if let Type::ImplTrait(_,) = &*arg.ty {
+        return Err(Error::new(arg.ty.span(), "`#[call]` methods cannot take `impl Trait` arguments, use a generic parameter",))
+      }
+      arg_tps.push((*arg.ty).clone(),)
+    }
+  }
+
+  let ret = match &sig.output {
+    ReturnType::Default => parse_quote! { () },
+    ReturnType::Type(_, ty,) => {
+      if let Type::ImplTrait(_,) = &**ty {
+        return Err(Error::new(ty.span(), "`#[call]` methods cannot return `impl Trait`",))
+      }
+
+      (**ty).clone()
+    },
+  };
+
+  Ok(CallFn { name: sig.ident.clone(), receiver, generics: sig.generics.clone(), arg_tps, ret, })
+}
+
+/// Combines the generics of the `impl` block with the generics of the method.
+fn merge_generics(item: &ItemImpl, call_fn: &CallFn,) -> Generics {
+  let mut generics = item.generics.clone();
+
+  generics.params.extend(call_fn.generics.params.iter().cloned(),);
+  if let Some(where_clause,) = &call_fn.generics.where_clause {
+    generics.make_where_clause().predicates.extend(where_clause.predicates.iter().cloned(),);
+  }
+
+  generics
+}
+
+fn impl_fns(item: &ItemImpl, call_fn: &CallFn,) -> TokenStream2 {
+  let generics = merge_generics(item, call_fn,);
+  let (impl_generics, _, where_clause,) = generics.split_for_impl();
+  let self_ty = &item.self_ty;
+  let CallFn { name, receiver, arg_tps, ret, .. } = call_fn;
+  let args = (0..arg_tps.len()).map(|i| format_ident!("arg{}", i,),).collect::<Vec<_>>();
+  let span = name.span();
+
+  let call_once = match receiver {
+    Receiver::Ref => quote_spanned! { span=> Self::#name(&self, #(#args,)*) },
+    Receiver::Mut => quote_spanned! { span=> Self::#name(&mut self, #(#args,)*) },
+    Receiver::Value => quote_spanned! { span=> Self::#name(self, #(#args,)*) },
+  };
+  let mut tokens = quote_spanned! { span=>
+    impl #impl_generics FnOnce<(#(#arg_tps,)*)> for #self_ty #where_clause {
+      type Output = #ret;
+
+      #[inline]
+      #[allow(unused_mut,)]
+      extern "rust-call" fn call_once(mut self, (#(#args,)*): (#(#arg_tps,)*),) -> Self::Output { #call_once }
+    }
+  };
+
+  if *receiver == Receiver::Value { return tokens }
+
+  let call_mut = match receiver {
+    Receiver::Ref => quote_spanned! { span=> Self::#name(&*self, #(#args,)*) },
+    _ => quote_spanned! { span=> Self::#name(self, #(#args,)*) },
+  };
+  tokens.extend(quote_spanned! { span=>
+    impl #impl_generics FnMut<(#(#arg_tps,)*)> for #self_ty #where_clause {
+      #[inline]
+      extern "rust-call" fn call_mut(&mut self, (#(#args,)*): (#(#arg_tps,)*),) -> Self::Output { #call_mut }
+    }
+  },);
+
+  if *receiver == Receiver::Mut { return tokens }
+
+  tokens.extend(quote_spanned! { span=>
+    impl #impl_generics Fn<(#(#arg_tps,)*)> for #self_ty #where_clause {
+      #[inline]
+      extern "rust-call" fn call(&self, (#(#args,)*): (#(#arg_tps,)*),) -> Self::Output { Self::#name(self, #(#args,)*) }
+    }
+  },);
+
+  tokens
+}
+
+fn impl_inherent_call(item: &ItemImpl, call_fn: &CallFn,) -> TokenStream2 {
+  if call_fn.name == "call" { return TokenStream2::new() }
+
+  let (impl_generics, _, where_clause,) = item.generics.split_for_impl();
+  let (method_generics, _, method_where,) = call_fn.generics.split_for_impl();
+  let self_ty = &item.self_ty;
+  let CallFn { name, receiver, arg_tps, ret, .. } = call_fn;
+  let args = (0..arg_tps.len()).map(|i| format_ident!("arg{}", i,),).collect::<Vec<_>>();
+  let receiver = match receiver {
+    Receiver::Ref => quote! { &self },
+    Receiver::Mut => quote! { &mut self },
+    Receiver::Value => quote! { self },
+  };
+
+  quote! {
+    impl #impl_generics #self_ty #where_clause {
+      /// Calls this function object.
+      #[inline]
+      pub fn call #method_generics (#receiver, #(#args: #arg_tps,)*) -> #ret #method_where { self.#name(#(#args,)*) }
+    }
+  }
+}
@@ -0,0 +1,131 @@
+//! Author --- DMorgan  
+//! Last Moddified --- 2026-10-19
+
+use crate::*;
+use core::fmt;
+
+/// The error returned when a slice does not hold exactly as many arguments as a function takes.
+/// 
+/// ```rust
+/// use curry::*;
+/// 
+/// let f = Curry3(|a: i32, b: i32, c: i32| a + b + c);
+/// assert_eq!(f.apply_slice(&[1, 2, 3,]), Ok(6));
+/// assert_eq!(f.apply_slice(&[1, 2,]), Err(ArityError { expected: 3, found: 2, }));
+/// assert_eq!(f.apply_array([1, 2, 3,]), 6);
+/// 
+/// let mut args = 1..;
+/// assert!(matches!(f.apply_iter(&mut args), Partial3::Done(6)));
+/// assert_eq!(args.next(), Some(4));
+/// match f.apply_iter([1, 2,]) {
+///   Partial3::Args2(g) => assert_eq!(g(3), 6),
+///   _ => unreachable!(),
+/// }
+/// ```
+#[derive(PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Copy, Debug,)]
+pub struct ArityError {
+  /// The number of arguments the function takes.
+  pub expected: usize,
+  /// The number of arguments provided.
+  pub found: usize,
+}
+
+impl fmt::Display for ArityError {
+  fn fmt(&self, fmt: &mut fmt::Formatter,) -> fmt::Result {
+    write!(fmt, "expected {} arguments, found {}", self.expected, self.found,)
+  }
+}
+
+#[cfg(feature = "std",)]
+impl std::error::Error for ArityError {}
+
+/// Defines the result of `apply_iter` and the homogeneous application methods for a single arity.
+macro_rules! impl_apply {
+  ($curry:ident $partial:ident $n:literal $self_:ident ($($t:ident,)+) {
+    $($arg:ident => $var:ident($tp:ty) = $short:expr,)+
+  }) => {
+    /// The result of applying the arguments of an iterator to a
+    #[doc = concat!("`", stringify!($curry), "`.",)]
+    #[derive(PartialEq, Eq, Clone, Copy, Debug,)]
+    pub enum $partial<T, F, R,> {
+      /// Every argument was applied.
+      Done(R),
+      $(
+        /// The iterator ran short leaving the partially applied function.
+        $var($tp),
+      )+
+    }
+
+    impl<F,> $curry<F,> {
+      /// Applies every element of `args` as an argument.
+      #[inline]
+      pub fn apply_array<T,>($self_, [$($arg,)+]: [T; $n],) -> F::Output
+        where F: FnOnce<($($t,)+)>, {
+        ($self_.0)($($arg,)+)
+      }
+      /// Applies clones of the elements of `args` as arguments, `args` must hold exactly as many
+      /// elements as the function takes.
+      #[inline]
+      pub fn apply_slice<T,>($self_, args: &[T],) -> Result<F::Output, ArityError,>
+        where T: Clone,
+          F: FnOnce<($($t,)+)>, {
+        match args {
+          [$($arg,)+] => Ok(($self_.0)($($arg.clone(),)+),),
+          _ => Err(ArityError { expected: $n, found: args.len(), },),
+        }
+      }
+      /// Applies items taken from `args`, returning the partially applied function if it runs
+      /// short.
+      /// 
+      /// No more items are taken than the function takes arguments.
+      pub fn apply_iter<I,>($self_, args: I,) -> $partial<I::Item, F, F::Output,>
+        where I: IntoIterator,
+          F: FnOnce<($(impl_apply!(@item $t I),)+)>, {
+        let mut args = args.into_iter();
+
+        $(let $arg = match args.next() {
+          Some(arg) => arg,
+          None => return $partial::$var($short,),
+        };)+
+
+        $partial::Done(($self_.0)($($arg,)+),)
+      }
+    }
+  };
+  (@item $t:ident $iter:ident) => { $iter::Item };
+}
+
+impl_apply!(Curry2 Partial2 2 self (T, T,) {
+  a => Args0(Curry2<F,>) = self,
+  b => Args1(Closure2<T, F,>) = Closure2::new(a, self.0,),
+});
+
+impl_apply!(Curry3 Partial3 3 self (T, T, T,) {
+  a => Args0(Curry3<F,>) = self,
+  b => Args1(Closure2<T, Curry3<F,>,>) = Closure2::new(a, self,),
+  c => Args2(Closure3<T, T, F,>) = Closure3::new(a, b, self.0,),
+});
+
+impl_apply!(Curry4 Partial4 4 self (T, T, T, T,) {
+  a => Args0(Curry4<F,>) = self,
+  b => Args1(Closure2<T, Curry4<F,>,>) = Closure2::new(a, self,),
+  c => Args2(Closure3<T, T, Curry4<F,>,>) = Closure3::new(a, b, self,),
+  d => Args3(Closure4<T, T, T, F,>) = Closure4::new(a, b, c, self.0,),
+});
+
+impl_apply!(Curry5 Partial5 5 self (T, T, T, T, T,) {
+  a => Args0(Curry5<F,>) = self,
+  b => Args1(Closure2<T, Curry5<F,>,>) = Closure2::new(a, self,),
+  c => Args2(Closure3<T, T, Curry5<F,>,>) = Closure3::new(a, b, self,),
+  d => Args3(Closure4<T, T, T, Curry5<F,>,>) = Closure4::new(a, b, c, self,),
+  e => Args4(Closure5<T, T, T, T, F,>) = Closure5::new(a, b, c, d, self.0,),
+});
+
+impl_apply!(Curry6 Partial6 6 self (T, T, T, T, T, T,) {
+  a => Args0(Curry6<F,>) = self,
+  b => Args1(Closure2<T, Curry6<F,>,>) = Closure2::new(a, self,),
+  c => Args2(Closure3<T, T, Curry6<F,>,>) = Closure3::new(a, b, self,),
+  d => Args3(Closure4<T, T, T, Curry6<F,>,>) = Closure4::new(a, b, c, self,),
+  e => Args4(Closure5<T, T, T, T, Curry6<F,>,>) = Closure5::new(a, b, c, d, self,),
+  f => Args5(Closure6<T, T, T, T, T, F,>) = Closure6::new(a, b, c, d, e, self.0,),
+});
@@ -0,0 +1,118 @@
+//! Author --- DMorgan  
+//! Last Moddified --- 2026-10-19
+
+use crate::*;
+use core::mem;
+
+/// A partially applied function whose bound argument at position `I` can be replaced.
+/// 
+/// Usually called through the `replace_arg` method of the `ClosureN` types.
+/// 
+/// ```rust
+/// use curry::*;
+/// 
+/// let mut f = Curry3(|a: i32, b: &str, c: i32| a + b.len() as i32 + c)(1, "ab");
+/// assert_eq!(f.applied(), (&1, &"ab",));
+/// assert_eq!(f.replace_arg::<1>("abcd"), "ab");
+/// assert_eq!(f(3), 8);
+/// 
+/// let g = f.as_ref();
+/// assert_eq!(g.cloned()(3), 8);
+/// assert_eq!(f.as_mut().copied().map_func(|f| move |a, b, c| f(a, b, c) * 2)(3), 16);
+/// 
+/// let (args, func,) = f.into_parts();
+/// assert_eq!(args, (1, "abcd",));
+/// assert_eq!(Closure3::from_parts((2, "",), func,)(3), 5);
+/// ```
+pub trait ReplaceArg<const I: usize,> {
+  /// The type of the argument at position `I`.
+  type Arg;
+
+  /// Replaces the argument at position `I` returning the previous argument.
+  fn replace_arg(&mut self, arg: Self::Arg,) -> Self::Arg;
+}
+
+/// Implements the argument and reference accessors of a `ClosureN` type.
+macro_rules! impl_args {
+  ($closure:ident $tps:tt => $($i:literal $tp:ident $arg:ident,)+) => {
+    impl<$($tp,)+ F,> $closure<$($tp,)+ F,> {
+      /// References the bound arguments.
+      #[inline]
+      pub const fn applied(&self,) -> ($(&$tp,)+) { ($(&self.$arg,)+) }
+      /// Splits the bound arguments from the function.
+      #[inline]
+      pub fn into_parts(self,) -> (($($tp,)+), F,) { (($(self.$arg,)+), self.func,) }
+      #[doc = concat!("Constructs a new `", stringify!($closure), "` from its bound arguments and function.",)]
+      #[inline]
+      pub fn from_parts(($($arg,)+): ($($tp,)+), func: F,) -> Self { $closure { $($arg,)+ func, } }
+      /// Replaces the bound argument at position `I` returning the previous argument.
+      #[inline]
+      pub fn replace_arg<const I: usize,>(&mut self, arg: <Self as ReplaceArg<I,>>::Arg,) -> <Self as ReplaceArg<I,>>::Arg
+        where Self: ReplaceArg<I,>, {
+        ReplaceArg::<I,>::replace_arg(self, arg,)
+      }
+    }
+
+    impl<$($tp,)+ F,> $closure<$($tp,)+ F,> {
+      /// References the bound arguments and the function.
+      #[inline]
+      pub const fn as_ref(&self,) -> $closure<$(&$tp,)+ &F,> { $closure { $($arg: &self.$arg,)+ func: &self.func, } }
+      /// Mutably references the bound arguments and the function.
+      #[inline]
+      pub const fn as_mut(&mut self,) -> $closure<$(&mut $tp,)+ &mut F,> { $closure { $($arg: &mut self.$arg,)+ func: &mut self.func, } }
+      /// Maps the function keeping the bound arguments.
+      #[inline]
+      pub fn map_func<G, M,>(self, map: M,) -> $closure<$($tp,)+ G,>
+        where M: FnOnce(F,) -> G, {
+        $closure { $($arg: self.$arg,)+ func: map(self.func,), }
+      }
+      /// Returns the function discarding the bound arguments.
+      #[inline]
+      pub fn into_inner(self,) -> F { self.func }
+    }
+
+    impl<$($tp,)+ F,> $closure<$(&'_ $tp,)+ &'_ F,>
+      where $($tp: Clone,)+ F: Clone, {
+      /// Clones the bound arguments and the function.
+      #[inline]
+      pub fn cloned(&self,) -> $closure<$($tp,)+ F,> { $closure { $($arg: self.$arg.clone(),)+ func: self.func.clone(), } }
+    }
+
+    impl<$($tp,)+ F,> $closure<$(&'_ $tp,)+ &'_ F,>
+      where $($tp: Copy,)+ F: Copy, {
+      /// Copies the bound arguments and the function.
+      #[inline]
+      pub fn copied(&self,) -> $closure<$($tp,)+ F,> { $closure { $($arg: *self.$arg,)+ func: *self.func, } }
+    }
+
+    impl<$($tp,)+ F,> $closure<$(&'_ mut $tp,)+ &'_ mut F,>
+      where $($tp: Clone,)+ F: Clone, {
+      /// Clones the bound arguments and the function.
+      #[inline]
+      pub fn cloned(&self,) -> $closure<$($tp,)+ F,> { $closure { $($arg: self.$arg.clone(),)+ func: self.func.clone(), } }
+    }
+
+    impl<$($tp,)+ F,> $closure<$(&'_ mut $tp,)+ &'_ mut F,>
+      where $($tp: Copy,)+ F: Copy, {
+      /// Copies the bound arguments and the function.
+      #[inline]
+      pub fn copied(&self,) -> $closure<$($tp,)+ F,> { $closure { $($arg: *self.$arg,)+ func: *self.func, } }
+    }
+
+    $(impl_args!(@replace $closure $tps $i $tp $arg);)+
+  };
+  (@replace $closure:ident [$($tps:ident,)+] $i:literal $tp:ident $arg:ident) => {
+    impl<$($tps,)+ F,> ReplaceArg<$i,> for $closure<$($tps,)+ F,> {
+      type Arg = $tp;
+
+      #[inline]
+      fn replace_arg(&mut self, arg: Self::Arg,) -> Self::Arg { mem::replace(&mut self.$arg, arg,) }
+    }
+  };
+}
+
+impl_args!(Closure2 [A,] => 0 A a,);
+impl_args!(Closure3 [A, B,] => 0 A a, 1 B b,);
+impl_args!(Closure4 [A, B, C,] => 0 A a, 1 B b, 2 C c,);
+impl_args!(Closure5 [A, B, C, D,] => 0 A a, 1 B b, 2 C c, 3 D d,);
+impl_args!(Closure6 [A, B, C, D, E,] => 0 A a, 1 B b, 2 C c, 3 D d, 4 E e,);
@@ -0,0 +1,26 @@
+//! Author --- DMorgan  
+//! Last Moddified --- 2026-10-19
+
+/// A stable equivalent of `FnOnce`.
+/// 
+/// On a stable toolchain [`impl_fn`](crate::impl_fn) implements these traits in place of the
+/// `Fn*` traits.
+pub trait CallOnce<Args,> {
+  /// The returned type.
+  type Output;
+
+  /// Calls the function by value.
+  fn call_once(self, args: Args,) -> Self::Output;
+}
+
+/// A stable equivalent of `FnMut`.
+pub trait CallMut<Args,>: CallOnce<Args,> {
+  /// Calls the function by mutable reference.
+  fn call_mut(&mut self, args: Args,) -> Self::Output;
+}
+
+/// A stable equivalent of `Fn`.
+pub trait Call<Args,>: CallMut<Args,> {
+  /// Calls the function by reference.
+  fn call(&self, args: Args,) -> Self::Output;
+}
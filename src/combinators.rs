@@ -0,0 +1,508 @@
+//! Author --- DMorgan  
+//! Last Moddified --- 2026-10-19
+
+use core::{fmt, marker::PhantomData,};
+
+/// The identity function (`I`).
+/// 
+/// ```rust
+/// use curry::*;
+/// 
+/// assert_eq!(Id(1), 1);
+/// assert_eq!(Compose(Id, |a: i32| a + 1)(1), 2);
+/// ```
+#[derive(PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Copy, Default, Debug,)]
+pub struct Id;
+
+impl<A,> FnOnce<(A,)> for Id {
+  type Output = A;
+
+  #[inline]
+  extern "rust-call" fn call_once(self, (a,): (A,),) -> Self::Output { a }
+}
+
+impl<A,> FnMut<(A,)> for Id {
+  #[inline]
+  extern "rust-call" fn call_mut(&mut self, (a,): (A,),) -> Self::Output { a }
+}
+
+impl<A,> Fn<(A,)> for Id {
+  #[inline]
+  extern "rust-call" fn call(&self, (a,): (A,),) -> Self::Output { a }
+}
+
+/// A function which ignores its argument and returns a constant (`K`).
+/// 
+/// ```rust
+/// use curry::*;
+/// 
+/// let f = Const(42);
+/// assert_eq!(f('c'), 42);
+/// assert_eq!(f("str"), 42);
+/// ```
+#[repr(transparent,)]
+#[derive(PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Copy, Default, Debug,)]
+pub struct Const<T,>(pub T,);
+
+impl<T,> Const<T,> {
+  /// Constructs a new `Const` returning `value`.
+  #[inline]
+  pub const fn new(value: T,) -> Self { Const(value,) }
+}
+
+impl<A, T,> FnOnce<(A,)> for Const<T,> {
+  type Output = T;
+
+  #[inline]
+  extern "rust-call" fn call_once(self, _: (A,),) -> Self::Output { self.0 }
+}
+
+impl<A, T,> FnMut<(A,)> for Const<T,>
+  where T: Clone, {
+  #[inline]
+  extern "rust-call" fn call_mut(&mut self, _: (A,),) -> Self::Output { self.0.clone() }
+}
+
+impl<A, T,> Fn<(A,)> for Const<T,>
+  where T: Clone, {
+  #[inline]
+  extern "rust-call" fn call(&self, _: (A,),) -> Self::Output { self.0.clone() }
+}
+
+/// A binary function with its arguments swapped (`C`).
+/// 
+/// ```rust
+/// use curry::*;
+/// 
+/// let f = Curry2(Flip(|a: i32, b: i32| a - b));
+/// assert_eq!(f(1, 3), 2);
+/// assert_eq!(f(1)(3), 2);
+/// ```
+#[repr(transparent,)]
+#[derive(PartialEq, Eq, Clone, Copy, Default, Debug,)]
+pub struct Flip<F,>(pub F,)
+  where F: ?Sized,;
+
+impl<F,> Flip<F,> {
+  /// Constructs a new `Flip` from `f`.
+  #[inline]
+  pub const fn new(f: F,) -> Self { Flip(f,) }
+}
+
+impl<A, B, F,> FnOnce<(A, B,)> for Flip<F,>
+  where F: FnOnce<(B, A,)>, {
+  type Output = F::Output;
+
+  #[inline]
+  extern "rust-call" fn call_once(self, (a, b,): (A, B,),) -> Self::Output { self.0.call_once((b, a,),) }
+}
+
+impl<A, B, F,> FnMut<(A, B,)> for Flip<F,>
+  where F: FnMut<(B, A,)>, {
+  #[inline]
+  extern "rust-call" fn call_mut(&mut self, (a, b,): (A, B,),) -> Self::Output { self.0.call_mut((b, a,),) }
+}
+
+impl<A, B, F,> Fn<(A, B,)> for Flip<F,>
+  where F: Fn<(B, A,)>, {
+  #[inline]
+  extern "rust-call" fn call(&self, (a, b,): (A, B,),) -> Self::Output { self.0.call((b, a,),) }
+}
+
+/// The composition of two functions (`B`), `Compose(f, g)(args) == f(g(args))`.
+/// 
+/// `g` may take up to 6 arguments.
+/// 
+/// ```rust
+/// use curry::*;
+/// 
+/// let f = Curry2(Compose(|a: i32| a * 2, |a: i32, b: i32| a + b));
+/// assert_eq!(f(1, 2), 6);
+/// assert_eq!(f(1)(2), 6);
+/// ```
+#[derive(PartialEq, Eq, Clone, Copy, Default, Debug,)]
+pub struct Compose<F, G,>(pub F, pub G,);
+
+impl<F, G,> Compose<F, G,> {
+  /// Constructs a new `Compose` applying `f` to the result of `g`.
+  #[inline]
+  pub const fn new(f: F, g: G,) -> Self { Compose(f, g,) }
+}
+
+/// Implements the `Fn*` traits for `Compose` for a single arity.
+macro_rules! impl_compose {
+  ($($arg_tp:ident $arg:ident,)*) => {
+    impl<$($arg_tp,)* F, G,> FnOnce<($($arg_tp,)*)> for Compose<F, G,>
+      where G: FnOnce<($($arg_tp,)*)>,
+        F: FnOnce<(G::Output,)>, {
+      type Output = F::Output;
+
+      #[inline]
+      extern "rust-call" fn call_once(self, args: ($($arg_tp,)*),) -> Self::Output { self.0.call_once((self.1.call_once(args,),),) }
+    }
+
+    impl<$($arg_tp,)* F, G,> FnMut<($($arg_tp,)*)> for Compose<F, G,>
+      where G: FnMut<($($arg_tp,)*)>,
+        F: FnMut<(G::Output,)>, {
+      #[inline]
+      extern "rust-call" fn call_mut(&mut self, args: ($($arg_tp,)*),) -> Self::Output { self.0.call_mut((self.1.call_mut(args,),),) }
+    }
+
+    impl<$($arg_tp,)* F, G,> Fn<($($arg_tp,)*)> for Compose<F, G,>
+      where G: Fn<($($arg_tp,)*)>,
+        F: Fn<(G::Output,)>, {
+      #[inline]
+      extern "rust-call" fn call(&self, args: ($($arg_tp,)*),) -> Self::Output { self.0.call((self.1.call(args,),),) }
+    }
+  };
+}
+
+impl_compose!();
+impl_compose!(A a,);
+impl_compose!(A a, B b,);
+impl_compose!(A a, B b, C c,);
+impl_compose!(A a, B b, C c, D d,);
+impl_compose!(A a, B b, C c, D d, E e,);
+impl_compose!(A a, B b, C c, D d, E e, H h,);
+
+/// Applies a function to an argument and the result of another function of the same argument
+/// (`S`), `Substitute(f, g)(x) == f(x, g(x))`.
+/// 
+/// ```rust
+/// use curry::*;
+/// 
+/// let f = Substitute(|a: i32, b: i32| a * b, |a: i32| a + 1);
+/// assert_eq!(f(2), 6);
+/// ```
+#[derive(PartialEq, Eq, Clone, Copy, Default, Debug,)]
+pub struct Substitute<F, G,>(pub F, pub G,);
+
+impl<F, G,> Substitute<F, G,> {
+  /// Constructs a new `Substitute` from `f` and `g`.
+  #[inline]
+  pub const fn new(f: F, g: G,) -> Self { Substitute(f, g,) }
+}
+
+impl<A, F, G,> FnOnce<(A,)> for Substitute<F, G,>
+  where A: Clone,
+    G: FnOnce<(A,)>,
+    F: FnOnce<(A, G::Output,)>, {
+  type Output = F::Output;
+
+  #[inline]
+  extern "rust-call" fn call_once(self, (a,): (A,),) -> Self::Output { self.0.call_once((a.clone(), self.1.call_once((a,),),),) }
+}
+
+impl<A, F, G,> FnMut<(A,)> for Substitute<F, G,>
+  where A: Clone,
+    G: FnMut<(A,)>,
+    F: FnMut<(A, G::Output,)>, {
+  #[inline]
+  extern "rust-call" fn call_mut(&mut self, (a,): (A,),) -> Self::Output { self.0.call_mut((a.clone(), self.1.call_mut((a,),),),) }
+}
+
+impl<A, F, G,> Fn<(A,)> for Substitute<F, G,>
+  where A: Clone,
+    G: Fn<(A,)>,
+    F: Fn<(A, G::Output,)>, {
+  #[inline]
+  extern "rust-call" fn call(&self, (a,): (A,),) -> Self::Output { self.0.call((a.clone(), self.1.call((a,),),),) }
+}
+
+/// Applies its first argument to the remaining arguments, `Apply(f, a, b) == f(a, b)`.
+/// 
+/// ```rust
+/// use curry::*;
+/// 
+/// assert_eq!(Apply(|a: i32, b: i32| a + b, 1, 2), 3);
+/// assert_eq!(Curry2(Apply)(|a: i32| a + 1)(1), 2);
+/// ```
+#[derive(PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Copy, Default, Debug,)]
+pub struct Apply;
+
+/// Implements the `Fn*` traits for `Apply` for a single arity.
+macro_rules! impl_apply {
+  ($($arg_tp:ident $arg:ident,)*) => {
+    impl<$($arg_tp,)* F,> FnOnce<(F, $($arg_tp,)*)> for Apply
+      where F: FnOnce<($($arg_tp,)*)>, {
+      type Output = F::Output;
+
+      #[inline]
+      extern "rust-call" fn call_once(self, (f, $($arg,)*): (F, $($arg_tp,)*),) -> Self::Output { f.call_once(($($arg,)*),) }
+    }
+
+    impl<$($arg_tp,)* F,> FnMut<(F, $($arg_tp,)*)> for Apply
+      where F: FnOnce<($($arg_tp,)*)>, {
+      #[inline]
+      extern "rust-call" fn call_mut(&mut self, (f, $($arg,)*): (F, $($arg_tp,)*),) -> Self::Output { f.call_once(($($arg,)*),) }
+    }
+
+    impl<$($arg_tp,)* F,> Fn<(F, $($arg_tp,)*)> for Apply
+      where F: FnOnce<($($arg_tp,)*)>, {
+      #[inline]
+      extern "rust-call" fn call(&self, (f, $($arg,)*): (F, $($arg_tp,)*),) -> Self::Output { f.call_once(($($arg,)*),) }
+    }
+  };
+}
+
+impl_apply!();
+impl_apply!(A a,);
+impl_apply!(A a, B b,);
+impl_apply!(A a, B b, C c,);
+impl_apply!(A a, B b, C c, D d,);
+impl_apply!(A a, B b, C c, D d, E e,);
+
+/// Applies a binary function to its arguments after mapping both, `On(f, g)(a, b) == f(g(a), g(b))`.
+/// 
+/// ```rust
+/// use curry::*;
+/// 
+/// let f = Curry2(On(|a: usize, b: usize| a + b, |s: &str| s.len()));
+/// assert_eq!(f("ab", "cde"), 5);
+/// assert_eq!(f("ab")("cde"), 5);
+/// ```
+#[derive(PartialEq, Eq, Clone, Copy, Default, Debug,)]
+pub struct On<F, G,>(pub F, pub G,);
+
+impl<F, G,> On<F, G,> {
+  /// Constructs a new `On` applying `f` to the arguments mapped by `g`.
+  #[inline]
+  pub const fn new(f: F, g: G,) -> Self { On(f, g,) }
+}
+
+impl<A, F, G,> FnOnce<(A, A,)> for On<F, G,>
+  where G: FnMut<(A,)>,
+    F: FnOnce<(G::Output, G::Output,)>, {
+  type Output = F::Output;
+
+  #[inline]
+  extern "rust-call" fn call_once(mut self, (a, b,): (A, A,),) -> Self::Output {
+    let (a, b,) = (self.1.call_mut((a,),), self.1.call_mut((b,),),);
+
+    self.0.call_once((a, b,),)
+  }
+}
+
+impl<A, F, G,> FnMut<(A, A,)> for On<F, G,>
+  where G: FnMut<(A,)>,
+    F: FnMut<(G::Output, G::Output,)>, {
+  #[inline]
+  extern "rust-call" fn call_mut(&mut self, (a, b,): (A, A,),) -> Self::Output {
+    let (a, b,) = (self.1.call_mut((a,),), self.1.call_mut((b,),),);
+
+    self.0.call_mut((a, b,),)
+  }
+}
+
+impl<A, F, G,> Fn<(A, A,)> for On<F, G,>
+  where G: Fn<(A,)>,
+    F: Fn<(G::Output, G::Output,)>, {
+  #[inline]
+  extern "rust-call" fn call(&self, (a, b,): (A, A,),) -> Self::Output { self.0.call((self.1.call((a,),), self.1.call((b,),),),) }
+}
+
+/// The fixpoint of a function (`Y`), `func` receives the `Fix` itself to recurse with.
+/// 
+/// Constructed with [`fix`](crate::fix) so that the type of the closure's `rec` argument can be
+/// inferred.
+/// 
+/// Each recursive call uses the stack, see [`Trampoline`] for deep tail recursion.
+/// 
+/// ```rust
+/// use curry::*;
+/// 
+/// let fact = fix!(|rec, n: u64| if n == 0 { 1 } else { n * rec(n - 1) });
+/// assert_eq!(fact(5), 120);
+/// assert_eq!(Compose(fact, Id)(3), 6);
+/// 
+/// let fact = Curry2(fix!(|rec, acc: u64, n: u64| if n == 0 { acc } else { rec(acc * n, n - 1) }));
+/// assert_eq!(fact(1, 5), 120);
+/// assert_eq!(fact(1)(5), 120);
+/// ```
+pub struct Fix<F, Args, R,>
+  where F: ?Sized, {
+  /// The signature of the fixpoint.
+  sig: PhantomData<fn(Args,) -> R>,
+  /// The function being wrapped.
+  pub func: F,
+}
+
+impl<F, Args, R,> Fix<F, Args, R,> {
+  /// Constructs a new `Fix` from `func`.
+  #[inline]
+  pub const fn new(func: F,) -> Self { Fix { sig: PhantomData, func, } }
+}
+
+impl<F, Args, R,> PartialEq for Fix<F, Args, R,>
+  where F: PartialEq + ?Sized, {
+  #[inline]
+  fn eq(&self, rhs: &Self,) -> bool { self.func == rhs.func }
+}
+
+impl<F, Args, R,> Eq for Fix<F, Args, R,>
+  where F: Eq + ?Sized, {}
+
+impl<F, Args, R,> Clone for Fix<F, Args, R,>
+  where F: Clone, {
+  #[inline]
+  fn clone(&self,) -> Self { Fix::new(self.func.clone(),) }
+}
+
+impl<F, Args, R,> Copy for Fix<F, Args, R,>
+  where F: Copy, {}
+
+impl<F, Args, R,> Default for Fix<F, Args, R,>
+  where F: Default, {
+  #[inline]
+  fn default() -> Self { Fix::new(F::default(),) }
+}
+
+impl<F, Args, R,> fmt::Debug for Fix<F, Args, R,>
+  where F: fmt::Debug + ?Sized, {
+  fn fmt(&self, fmt: &mut fmt::Formatter,) -> fmt::Result {
+    fmt.debug_struct("Fix",).field("func", &&self.func,).finish()
+  }
+}
+
+/// Implements the `Fn*` traits for `Fix` and its constructor function for a single arity.
+macro_rules! impl_fix {
+  ($ctor:ident $doc:literal => $($arg_tp:ident $arg:ident,)+) => {
+    #[doc = $doc]
+    #[doc(hidden,)]
+    #[inline]
+    pub const fn $ctor<$($arg_tp,)+ R, F,>(func: F,) -> Fix<F, ($($arg_tp,)+), R,>
+      where F: Fn(&dyn Fn($($arg_tp,)+) -> R, $($arg_tp,)+) -> R, {
+      Fix::new(func,)
+    }
+
+    impl<$($arg_tp,)+ R, F,> FnOnce<($($arg_tp,)+)> for Fix<F, ($($arg_tp,)+), R,>
+      where F: Fn(&dyn Fn($($arg_tp,)+) -> R, $($arg_tp,)+) -> R, {
+      type Output = R;
+
+      #[inline]
+      extern "rust-call" fn call_once(self, args: ($($arg_tp,)+),) -> Self::Output { self.call(args,) }
+    }
+
+    impl<$($arg_tp,)+ R, F,> FnMut<($($arg_tp,)+)> for Fix<F, ($($arg_tp,)+), R,>
+      where F: Fn(&dyn Fn($($arg_tp,)+) -> R, $($arg_tp,)+) -> R, {
+      #[inline]
+      extern "rust-call" fn call_mut(&mut self, args: ($($arg_tp,)+),) -> Self::Output { self.call(args,) }
+    }
+
+    impl<$($arg_tp,)+ R, F,> Fn<($($arg_tp,)+)> for Fix<F, ($($arg_tp,)+), R,>
+      where F: Fn(&dyn Fn($($arg_tp,)+) -> R, $($arg_tp,)+) -> R, {
+      #[inline]
+      extern "rust-call" fn call(&self, ($($arg,)+): ($($arg_tp,)+),) -> Self::Output { (self.func)(self, $($arg,)+) }
+    }
+  };
+}
+
+impl_fix!(fix1 "Constructs the fixpoint of a unary function, used by `fix`." => A a,);
+impl_fix!(fix2 "Constructs the fixpoint of a binary function, used by `fix`." => A a, B b,);
+impl_fix!(fix3 "Constructs the fixpoint of a function of 3 arguments, used by `fix`." => A a, B b, C c,);
+impl_fix!(fix4 "Constructs the fixpoint of a function of 4 arguments, used by `fix`." => A a, B b, C c, D d,);
+impl_fix!(fix5 "Constructs the fixpoint of a function of 5 arguments, used by `fix`." => A a, B b, C c, D d, E e,);
+impl_fix!(fix6 "Constructs the fixpoint of a function of 6 arguments, used by `fix`." => A a, B b, C c, D d, E e, G g,);
+
+/// Constructs the [`Fix`](crate::Fix) of a closure `|rec, args..| body` of 1 to 6 arguments.
+/// 
+/// The type of `rec` can only be inferred if the number of arguments is known so a single
+/// function can not accept every arity; the macro counts the arguments to pick the arity.
+/// 
+/// ```rust
+/// use curry::*;
+/// 
+/// let fact = fix!(|rec, n: u64| if n == 0 { 1 } else { n * rec(n - 1) });
+/// assert_eq!(fact(5), 120);
+/// 
+/// let fact = Curry2(fix!(|rec, acc: u64, n: u64| if n == 0 { acc } else { rec(acc * n, n - 1) }));
+/// assert_eq!(fact(1)(5), 120);
+/// ```
+#[macro_export(local_inner_macros,)]
+macro_rules! fix {
+  (@fix [$a:tt] $closure:expr) => { $crate::fix1($closure,) };
+  (@fix [$a:tt $b:tt] $closure:expr) => { $crate::fix2($closure,) };
+  (@fix [$a:tt $b:tt $c:tt] $closure:expr) => { $crate::fix3($closure,) };
+  (@fix [$a:tt $b:tt $c:tt $d:tt] $closure:expr) => { $crate::fix4($closure,) };
+  (@fix [$a:tt $b:tt $c:tt $d:tt $e:tt] $closure:expr) => { $crate::fix5($closure,) };
+  (@fix [$a:tt $b:tt $c:tt $d:tt $e:tt $f:tt] $closure:expr) => { $crate::fix6($closure,) };
+  (|$rec:ident $(, $arg:ident $(: $arg_tp:ty)?)+ $(,)?| $($body:tt)+) => {
+    fix!(@fix [$($arg)+] |$rec $(, $arg $(: $arg_tp)?)+| $($body)+)
+  };
+}
+
+/// A single step of a [`Trampoline`].
+#[derive(PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Copy, Debug,)]
+pub enum Step<Args, R,> {
+  /// Recursion has finished with the result.
+  Done(R),
+  /// Recurse with the new arguments.
+  Recur(Args),
+}
+
+/// A tail recursive function which runs in constant stack space.
+/// 
+/// Instead of calling itself `func` returns the next [`Step`]; it is called again with the
+/// arguments of each `Step::Recur` until it returns `Step::Done`.
+/// 
+/// ```rust
+/// use curry::*;
+/// 
+/// let sum = Curry2(Trampoline(|acc: u64, n: u64| if n == 0 { Step::Done(acc) } else { Step::Recur((acc + n, n - 1,)) }));
+/// assert_eq!(sum(0, 1_000_000), 500_000_500_000);
+/// assert_eq!(sum(0)(4), 10);
+/// ```
+#[repr(transparent,)]
+#[derive(PartialEq, Eq, Clone, Copy, Default, Debug,)]
+pub struct Trampoline<F,>(pub F,)
+  where F: ?Sized,;
+
+impl<F,> Trampoline<F,> {
+  /// Constructs a new `Trampoline` from `f`.
+  #[inline]
+  pub const fn new(f: F,) -> Self { Trampoline(f,) }
+}
+
+/// Implements the `Fn*` traits for `Trampoline` for a single arity.
+macro_rules! impl_trampoline {
+  ($($arg_tp:ident,)+) => {
+    impl<$($arg_tp,)+ R, F,> FnOnce<($($arg_tp,)+)> for Trampoline<F,>
+      where F: FnMut<($($arg_tp,)+), Output = Step<($($arg_tp,)+), R,>>, {
+      type Output = R;
+
+      #[inline]
+      extern "rust-call" fn call_once(mut self, args: ($($arg_tp,)+),) -> Self::Output { self.call_mut(args,) }
+    }
+
+    impl<$($arg_tp,)+ R, F,> FnMut<($($arg_tp,)+)> for Trampoline<F,>
+      where F: FnMut<($($arg_tp,)+), Output = Step<($($arg_tp,)+), R,>>, {
+      extern "rust-call" fn call_mut(&mut self, mut args: ($($arg_tp,)+),) -> Self::Output {
+        loop {
+          match self.0.call_mut(args,) {
+            Step::Done(r) => return r,
+            Step::Recur(next) => args = next,
+          }
+        }
+      }
+    }
+
+    impl<$($arg_tp,)+ R, F,> Fn<($($arg_tp,)+)> for Trampoline<F,>
+      where F: Fn<($($arg_tp,)+), Output = Step<($($arg_tp,)+), R,>>, {
+      extern "rust-call" fn call(&self, mut args: ($($arg_tp,)+),) -> Self::Output {
+        loop {
+          match self.0.call(args,) {
+            Step::Done(r) => return r,
+            Step::Recur(next) => args = next,
+          }
+        }
+      }
+    }
+  };
+}
+
+impl_trampoline!(A,);
+impl_trampoline!(A, B,);
+impl_trampoline!(A, B, C,);
+impl_trampoline!(A, B, C, D,);
+impl_trampoline!(A, B, C, D, E,);
+impl_trampoline!(A, B, C, D, E, G,);
@@ -1,6 +1,7 @@
 //! Author --- DMorgan  
-//! Last Moddified --- 2021-03-01
+//! Last Moddified --- 2026-10-19
 
+use crate::*;
 use core::ops::CoerceUnsized;
 
 /// A curried binary function.
@@ -13,7 +14,7 @@ use core::ops::CoerceUnsized;
 /// assert_eq!(f(1)(2), 3);
 /// ```
 #[repr(transparent,)]
-#[derive(PartialEq, Eq, Clone, Copy, Default, Debug,)]
+#[derive(PartialEq, Eq, Hash, Clone, Copy, Default, Debug,)]
 pub struct Curry2<F,>(pub F,)
   where F: ?Sized,;
 
@@ -27,6 +28,48 @@ impl<F,> Curry2<F,> {
   /// Mutably references the inner value.
   #[inline]
   pub const fn as_mut(&mut self,) -> Curry2<&mut F,> { Curry2(&mut self.0,) }
+  /// Binds all of the arguments without calling the inner value.
+  #[inline]
+  pub fn defer<A, B,>(self, a: A, b: B,) -> Thunk<F, (A, B,),> { Thunk::new((a, b,), self.0,) }
+  /// Allows trailing arguments to be omitted in favour of `defaults`.
+  /// 
+  /// Positions given as [`__`] fall back to `Default::default()`.
+  #[inline]
+  pub fn with_defaults<Args, D,>(self, defaults: D,) -> WithDefaults<F, D, Args,> { WithDefaults::new(self.0, defaults,) }
+  /// Fixes the last argument leaving a function of the leading arguments.
+  #[inline]
+  pub fn apply_last<Z,>(self, z: Z,) -> RClosure2<Z, F,> { RClosure2::new(z, self.0,) }
+  /// Reports the partial applications and invocations of the function to `tracer`.
+  #[inline]
+  pub const fn traced_with<T,>(self, label: &'static str, tracer: T,) -> Traced<Self, T, 2,> { Traced::new(label, tracer, self,) }
+  /// Reports the partial applications and invocations of the function to the [`DefaultTracer`].
+  #[inline]
+  pub fn traced(self, label: &'static str,) -> Traced<Self, DefaultTracer, 2,> { Traced::new(label, default_tracer(), self,) }
+  /// Calls an [`UnsafeFn`] with all of its arguments.
+  /// 
+  /// # Safety
+  /// 
+  /// The safety requirements of the inner function must be upheld.
+  #[inline]
+  pub unsafe fn call_unsafe<A, B,>(&self, a: A, b: B,) -> F::Output
+    where F: UnsafeFn<(A, B,)>, {
+    self.0.call_unsafe((a, b,),)
+  }
+  /// Asserts that the inner [`UnsafeFn`] is safe to call allowing it to be partially applied.
+  /// 
+  /// # Safety
+  /// 
+  /// Calling the inner function must be safe for every argument it accepts.
+  #[inline]
+  pub unsafe fn assume_safe(self,) -> Curry2<AssumeSafe<F,>,> { Curry2(AssumeSafe::new(self.0,),) }
+  /// Applies the function to every pair of arguments from `xs` and `ys`.
+  #[inline]
+  pub fn grid<X, Y,>(self, xs: X, ys: Y,) -> Grid2<Self, X::IntoIter, Y::IntoIter,>
+    where X: IntoIterator,
+      Y: IntoIterator,
+      Self: FnMut<(X::Item,)>, {
+    Grid2::new(self, xs.into_iter(), ys.into_iter(),)
+  }
 }
 
 impl<F,> Curry2<&'_ F,>
@@ -94,8 +137,15 @@ impl<A, B, F,> Fn<(A, B,)> for Curry2<F,>
   extern "rust-call" fn call(&self, args: (A, B,),) -> Self::Output { self.0.call(args,) }
 }
 
+impl<B, F,> ApplyRight<(B,)> for Curry2<F,> {
+  type Output = RClosure2<B, F,>;
+
+  #[inline]
+  fn apply_right(self, (b,): (B,),) -> Self::Output { RClosure2::new(b, self.0,) }
+}
+
 impl<T, U,> CoerceUnsized<Curry2<U,>> for Curry2<T,>
-  where T: CoerceUnsized<U> + ?Sized,
+  where T: CoerceUnsized<U>,
     U: ?Sized, {}
 
 /// A closure making a binary function into a curried function.
@@ -106,7 +156,7 @@ impl<T, U,> CoerceUnsized<Curry2<U,>> for Curry2<T,>
 /// let f: Closure2<_, _> = Curry2(|a, b| a + b)(1);
 /// assert_eq!(f(2), 3);
 /// ```
-#[derive(PartialEq, Eq, Clone, Copy, Default, Debug,)]
+#[derive(PartialEq, Eq, Hash, Clone, Copy, Default, Debug,)]
 pub struct Closure2<A, F,>
   where F: ?Sized, {
   /// The stored `A` parameter.
@@ -142,7 +192,7 @@ impl<A, B, F,> Fn<(B,)> for Closure2<A, F,>
 }
 
 impl<A, T, U,> CoerceUnsized<Closure2<A, U,>> for Closure2<A, T,>
-  where T: CoerceUnsized<U> + ?Sized,
+  where T: CoerceUnsized<U>,
     U: ?Sized, {}
 
 #[allow(unused,)]
@@ -1,5 +1,5 @@
 //! Author --- DMorgan  
-//! Last Moddified --- 2021-03-01
+//! Last Moddified --- 2026-10-19
 
 use crate::*;
 use core::ops::CoerceUnsized;
@@ -15,7 +15,7 @@ use core::ops::CoerceUnsized;
 /// assert_eq!(f(1)(2)(3), 6);
 /// ```
 #[repr(transparent,)]
-#[derive(PartialEq, Eq, Clone, Copy, Default, Debug,)]
+#[derive(PartialEq, Eq, Hash, Clone, Copy, Default, Debug,)]
 pub struct Curry3<F,>(pub F,)
   where F: ?Sized,;
 
@@ -29,6 +29,50 @@ impl<F,> Curry3<F,> {
   /// Mutably references the inner value.
   #[inline]
   pub const fn as_mut(&mut self,) -> Curry3<&mut F,> { Curry3(&mut self.0,) }
+  /// Binds all of the arguments without calling the inner value.
+  #[inline]
+  pub fn defer<A, B, C,>(self, a: A, b: B, c: C,) -> Thunk<F, (A, B, C,),> { Thunk::new((a, b, c,), self.0,) }
+  /// Allows trailing arguments to be omitted in favour of `defaults`.
+  /// 
+  /// Positions given as [`__`] fall back to `Default::default()`.
+  #[inline]
+  pub fn with_defaults<Args, D,>(self, defaults: D,) -> WithDefaults<F, D, Args,> { WithDefaults::new(self.0, defaults,) }
+  /// Fixes the last argument leaving a function of the leading arguments.
+  #[inline]
+  pub fn apply_last<Z,>(self, z: Z,) -> Curry2<RClosure2<Z, F,>,> { Curry2(RClosure2::new(z, self.0,),) }
+  /// Reports the partial applications and invocations of the function to `tracer`.
+  #[inline]
+  pub const fn traced_with<T,>(self, label: &'static str, tracer: T,) -> Traced<Self, T, 3,> { Traced::new(label, tracer, self,) }
+  /// Reports the partial applications and invocations of the function to the [`DefaultTracer`].
+  #[inline]
+  pub fn traced(self, label: &'static str,) -> Traced<Self, DefaultTracer, 3,> { Traced::new(label, default_tracer(), self,) }
+  /// Calls an [`UnsafeFn`] with all of its arguments.
+  /// 
+  /// # Safety
+  /// 
+  /// The safety requirements of the inner function must be upheld.
+  #[inline]
+  pub unsafe fn call_unsafe<A, B, C,>(&self, a: A, b: B, c: C,) -> F::Output
+    where F: UnsafeFn<(A, B, C,)>, {
+    self.0.call_unsafe((a, b, c,),)
+  }
+  /// Asserts that the inner [`UnsafeFn`] is safe to call allowing it to be partially applied.
+  /// 
+  /// # Safety
+  /// 
+  /// Calling the inner function must be safe for every argument it accepts.
+  #[inline]
+  pub unsafe fn assume_safe(self,) -> Curry3<AssumeSafe<F,>,> { Curry3(AssumeSafe::new(self.0,),) }
+  /// Applies the function to every combination of arguments from `xs`, `ys` and `zs`.
+  #[inline]
+  pub fn grid<X, Y, Z,>(self, xs: X, ys: Y, zs: Z,) -> Grid3<Self, X::IntoIter, Y::IntoIter, Z::IntoIter,>
+    where X: IntoIterator,
+      Y: IntoIterator,
+      Z: IntoIterator,
+      Self: FnMut<(X::Item,)>,
+      <Self as FnOnce<(X::Item,)>>::Output: FnOnce<(Y::Item,)>, {
+    Grid3::new(self, xs.into_iter(), ys.into_iter(), zs.into_iter(),)
+  }
 }
 
 impl<F,> Curry3<&'_ F,>
@@ -113,8 +157,22 @@ impl<A, B, C, F,> Fn<(A, B, C,)> for Curry3<F,>
   extern "rust-call" fn call(&self, args: (A, B, C,),) -> Self::Output { self.0.call(args,) }
 }
 
+impl<C, F,> ApplyRight<(C,)> for Curry3<F,> {
+  type Output = Curry2<RClosure2<C, F,>,>;
+
+  #[inline]
+  fn apply_right(self, (c,): (C,),) -> Self::Output { Curry2(RClosure2::new(c, self.0,),) }
+}
+
+impl<B, C, F,> ApplyRight<(B, C,)> for Curry3<F,> {
+  type Output = RClosure3<B, C, F,>;
+
+  #[inline]
+  fn apply_right(self, (b, c,): (B, C,),) -> Self::Output { RClosure3::new(b, c, self.0,) }
+}
+
 impl<T, U,> CoerceUnsized<Curry3<U,>> for Curry3<T,>
-  where T: CoerceUnsized<U> + ?Sized,
+  where T: CoerceUnsized<U>,
     U: ?Sized, {}
 
 /// A closure making a ternary function a unary function.
@@ -125,7 +183,7 @@ impl<T, U,> CoerceUnsized<Curry3<U,>> for Curry3<T,>
 /// let f: Closure3<_, _, _> = Curry3(|a, b, c| a + b + c)(1, 2);
 /// assert_eq!(f(3), 6);
 /// ```
-#[derive(PartialEq, Eq, Clone, Copy, Default, Debug,)]
+#[derive(PartialEq, Eq, Hash, Clone, Copy, Default, Debug,)]
 pub struct Closure3<A, B, F,>
   where F: ?Sized, {
   /// The stored `A` parameter.
@@ -165,7 +223,7 @@ impl<A, B, C, F,> Fn<(C,)> for Closure3<A, B, F,>
 }
 
 impl<A, B, T, U,> CoerceUnsized<Closure3<A, B, U,>> for Closure3<A, B, T,>
-  where T: CoerceUnsized<U> + ?Sized,
+  where T: CoerceUnsized<U>,
     U: ?Sized, {}
 
 #[allow(unused,)]
@@ -1,5 +1,5 @@
 //! Author --- DMorgan  
-//! Last Moddified --- 2021-03-01
+//! Last Moddified --- 2026-10-19
 
 use crate::*;
 use core::ops::CoerceUnsized;
@@ -15,7 +15,7 @@ use core::ops::CoerceUnsized;
 /// assert_eq!(f(1)(2)(3)(4), 10);
 /// ```
 #[repr(transparent,)]
-#[derive(PartialEq, Eq, Clone, Copy, Default, Debug,)]
+#[derive(PartialEq, Eq, Hash, Clone, Copy, Default, Debug,)]
 pub struct Curry4<F,>(pub F,)
   where F: ?Sized,;
 
@@ -29,6 +29,40 @@ impl<F,> Curry4<F,> {
   /// Mutably references the inner value.
   #[inline]
   pub const fn as_mut(&mut self,) -> Curry4<&mut F,> { Curry4(&mut self.0,) }
+  /// Binds all of the arguments without calling the inner value.
+  #[inline]
+  pub fn defer<A, B, C, D,>(self, a: A, b: B, c: C, d: D,) -> Thunk<F, (A, B, C, D,),> { Thunk::new((a, b, c, d,), self.0,) }
+  /// Allows trailing arguments to be omitted in favour of `defaults`.
+  /// 
+  /// Positions given as [`__`] fall back to `Default::default()`.
+  #[inline]
+  pub fn with_defaults<Args, D,>(self, defaults: D,) -> WithDefaults<F, D, Args,> { WithDefaults::new(self.0, defaults,) }
+  /// Fixes the last argument leaving a function of the leading arguments.
+  #[inline]
+  pub fn apply_last<Z,>(self, z: Z,) -> Curry3<RClosure2<Z, F,>,> { Curry3(RClosure2::new(z, self.0,),) }
+  /// Reports the partial applications and invocations of the function to `tracer`.
+  #[inline]
+  pub const fn traced_with<T,>(self, label: &'static str, tracer: T,) -> Traced<Self, T, 4,> { Traced::new(label, tracer, self,) }
+  /// Reports the partial applications and invocations of the function to the [`DefaultTracer`].
+  #[inline]
+  pub fn traced(self, label: &'static str,) -> Traced<Self, DefaultTracer, 4,> { Traced::new(label, default_tracer(), self,) }
+  /// Calls an [`UnsafeFn`] with all of its arguments.
+  /// 
+  /// # Safety
+  /// 
+  /// The safety requirements of the inner function must be upheld.
+  #[inline]
+  pub unsafe fn call_unsafe<A, B, C, D,>(&self, a: A, b: B, c: C, d: D,) -> F::Output
+    where F: UnsafeFn<(A, B, C, D,)>, {
+    self.0.call_unsafe((a, b, c, d,),)
+  }
+  /// Asserts that the inner [`UnsafeFn`] is safe to call allowing it to be partially applied.
+  /// 
+  /// # Safety
+  /// 
+  /// Calling the inner function must be safe for every argument it accepts.
+  #[inline]
+  pub unsafe fn assume_safe(self,) -> Curry4<AssumeSafe<F,>,> { Curry4(AssumeSafe::new(self.0,),) }
 }
 
 impl<F,> Curry4<&'_ F,>
@@ -132,8 +166,29 @@ impl<A, B, C, D, F,> Fn<(A, B, C, D,)> for Curry4<F,>
   extern "rust-call" fn call(&self, args: (A, B, C, D,),) -> Self::Output { self.0.call(args,) }
 }
 
+impl<D, F,> ApplyRight<(D,)> for Curry4<F,> {
+  type Output = Curry3<RClosure2<D, F,>,>;
+
+  #[inline]
+  fn apply_right(self, (d,): (D,),) -> Self::Output { Curry3(RClosure2::new(d, self.0,),) }
+}
+
+impl<C, D, F,> ApplyRight<(C, D,)> for Curry4<F,> {
+  type Output = Curry2<RClosure3<C, D, F,>,>;
+
+  #[inline]
+  fn apply_right(self, (c, d,): (C, D,),) -> Self::Output { Curry2(RClosure3::new(c, d, self.0,),) }
+}
+
+impl<B, C, D, F,> ApplyRight<(B, C, D,)> for Curry4<F,> {
+  type Output = RClosure4<B, C, D, F,>;
+
+  #[inline]
+  fn apply_right(self, (b, c, d,): (B, C, D,),) -> Self::Output { RClosure4::new(b, c, d, self.0,) }
+}
+
 impl<T, U,> CoerceUnsized<Curry4<U,>> for Curry4<T,>
-  where T: CoerceUnsized<U> + ?Sized,
+  where T: CoerceUnsized<U>,
     U: ?Sized, {}
 
 /// A closure making a quaternary function a unary function.
@@ -144,7 +199,7 @@ impl<T, U,> CoerceUnsized<Curry4<U,>> for Curry4<T,>
 /// let f: Closure4<_, _, _, _> = Curry4(|a, b, c, d| a + b + c + d)(1, 2, 3);
 /// assert_eq!(f(4), 10);
 /// ```
-#[derive(PartialEq, Eq, Clone, Copy, Default, Debug,)]
+#[derive(PartialEq, Eq, Hash, Clone, Copy, Default, Debug,)]
 pub struct Closure4<A, B, C, F,>
   where F: ?Sized, {
   /// The stored `A` parameter.
@@ -188,7 +243,7 @@ impl<A, B, C, D, F,> Fn<(D,)> for Closure4<A, B, C, F,>
 }
 
 impl<A, B, C, T, U,> CoerceUnsized<Closure4<A, B, C, U,>> for Closure4<A, B, C, T,>
-  where T: CoerceUnsized<U> + ?Sized,
+  where T: CoerceUnsized<U>,
     U: ?Sized, {}
 
 #[allow(unused,)]
@@ -1,5 +1,5 @@
 //! Author --- DMorgan  
-//! Last Moddified --- 2021-03-01
+//! Last Moddified --- 2026-10-19
 
 use crate::*;
 use core::ops::CoerceUnsized;
@@ -17,7 +17,7 @@ use core::ops::CoerceUnsized;
 /// assert_eq!(f(1)(2)(3)(4)(5), 15);
 /// ```
 #[repr(transparent,)]
-#[derive(PartialEq, Eq, Clone, Copy, Default, Debug,)]
+#[derive(PartialEq, Eq, Hash, Clone, Copy, Default, Debug,)]
 pub struct Curry5<F,>(pub F,)
   where F: ?Sized,;
 
@@ -31,6 +31,40 @@ impl<F,> Curry5<F,> {
   /// Mutably references the inner value.
   #[inline]
   pub const fn as_mut(&mut self,) -> Curry5<&mut F,> { Curry5(&mut self.0,) }
+  /// Binds all of the arguments without calling the inner value.
+  #[inline]
+  pub fn defer<A, B, C, D, E,>(self, a: A, b: B, c: C, d: D, e: E,) -> Thunk<F, (A, B, C, D, E,),> { Thunk::new((a, b, c, d, e,), self.0,) }
+  /// Allows trailing arguments to be omitted in favour of `defaults`.
+  /// 
+  /// Positions given as [`__`] fall back to `Default::default()`.
+  #[inline]
+  pub fn with_defaults<Args, D,>(self, defaults: D,) -> WithDefaults<F, D, Args,> { WithDefaults::new(self.0, defaults,) }
+  /// Fixes the last argument leaving a function of the leading arguments.
+  #[inline]
+  pub fn apply_last<Z,>(self, z: Z,) -> Curry4<RClosure2<Z, F,>,> { Curry4(RClosure2::new(z, self.0,),) }
+  /// Reports the partial applications and invocations of the function to `tracer`.
+  #[inline]
+  pub const fn traced_with<T,>(self, label: &'static str, tracer: T,) -> Traced<Self, T, 5,> { Traced::new(label, tracer, self,) }
+  /// Reports the partial applications and invocations of the function to the [`DefaultTracer`].
+  #[inline]
+  pub fn traced(self, label: &'static str,) -> Traced<Self, DefaultTracer, 5,> { Traced::new(label, default_tracer(), self,) }
+  /// Calls an [`UnsafeFn`] with all of its arguments.
+  /// 
+  /// # Safety
+  /// 
+  /// The safety requirements of the inner function must be upheld.
+  #[inline]
+  pub unsafe fn call_unsafe<A, B, C, D, E,>(&self, a: A, b: B, c: C, d: D, e: E,) -> F::Output
+    where F: UnsafeFn<(A, B, C, D, E,)>, {
+    self.0.call_unsafe((a, b, c, d, e,),)
+  }
+  /// Asserts that the inner [`UnsafeFn`] is safe to call allowing it to be partially applied.
+  /// 
+  /// # Safety
+  /// 
+  /// Calling the inner function must be safe for every argument it accepts.
+  #[inline]
+  pub unsafe fn assume_safe(self,) -> Curry5<AssumeSafe<F,>,> { Curry5(AssumeSafe::new(self.0,),) }
 }
 
 impl<F,> Curry5<&'_ F,>
@@ -151,8 +185,36 @@ impl<A, B, C, D, E, F,> Fn<(A, B, C, D, E,)> for Curry5<F,>
   extern "rust-call" fn call(&self, args: (A, B, C, D, E,),) -> Self::Output { self.0.call(args,) }
 }
 
+impl<E, F,> ApplyRight<(E,)> for Curry5<F,> {
+  type Output = Curry4<RClosure2<E, F,>,>;
+
+  #[inline]
+  fn apply_right(self, (e,): (E,),) -> Self::Output { Curry4(RClosure2::new(e, self.0,),) }
+}
+
+impl<D, E, F,> ApplyRight<(D, E,)> for Curry5<F,> {
+  type Output = Curry3<RClosure3<D, E, F,>,>;
+
+  #[inline]
+  fn apply_right(self, (d, e,): (D, E,),) -> Self::Output { Curry3(RClosure3::new(d, e, self.0,),) }
+}
+
+impl<C, D, E, F,> ApplyRight<(C, D, E,)> for Curry5<F,> {
+  type Output = Curry2<RClosure4<C, D, E, F,>,>;
+
+  #[inline]
+  fn apply_right(self, (c, d, e,): (C, D, E,),) -> Self::Output { Curry2(RClosure4::new(c, d, e, self.0,),) }
+}
+
+impl<B, C, D, E, F,> ApplyRight<(B, C, D, E,)> for Curry5<F,> {
+  type Output = RClosure5<B, C, D, E, F,>;
+
+  #[inline]
+  fn apply_right(self, (b, c, d, e,): (B, C, D, E,),) -> Self::Output { RClosure5::new(b, c, d, e, self.0,) }
+}
+
 impl<T, U,> CoerceUnsized<Curry5<U,>> for Curry5<T,>
-  where T: CoerceUnsized<U> + ?Sized,
+  where T: CoerceUnsized<U>,
     U: ?Sized, {}
 
 /// A closure making a function of arity 5 a unary function.
@@ -163,7 +225,7 @@ impl<T, U,> CoerceUnsized<Curry5<U,>> for Curry5<T,>
 /// let f: Closure5<_, _, _, _, _> = Curry5(|a, b, c, d, e| a + b + c + d + e)(1, 2, 3, 4);
 /// assert_eq!(f(5), 15);
 /// ```
-#[derive(PartialEq, Eq, Clone, Copy, Default, Debug,)]
+#[derive(PartialEq, Eq, Hash, Clone, Copy, Default, Debug,)]
 pub struct Closure5<A, B, C, D, F,>
   where F: ?Sized, {
   /// The stored `A` parameter.
@@ -215,7 +277,7 @@ impl<A, B, C, D, E, F,> Fn<(E,)> for Closure5<A, B, C, D, F,>
 }
 
 impl<A, B, C, D, T, U,> CoerceUnsized<Closure5<A, B, C, D, U,>> for Closure5<A, B, C, D, T,>
-  where T: CoerceUnsized<U> + ?Sized,
+  where T: CoerceUnsized<U>,
     U: ?Sized, {}
 
 #[allow(unused,)]
@@ -1,5 +1,5 @@
 //! Author --- DMorgan  
-//! Last Moddified --- 2021-03-02
+//! Last Moddified --- 2026-10-19
 
 use crate::*;
 use core::ops::CoerceUnsized;
@@ -18,7 +18,7 @@ use core::ops::CoerceUnsized;
 /// assert_eq!(f(1)(2)(3)(4)(5)(6), 21);
 /// ```
 #[repr(transparent,)]
-#[derive(PartialEq, Eq, Clone, Copy, Default, Debug,)]
+#[derive(PartialEq, Eq, Hash, Clone, Copy, Default, Debug,)]
 pub struct Curry6<F,>(pub F,)
   where F: ?Sized,;
 
@@ -32,6 +32,43 @@ impl<F,> Curry6<F,> {
   /// Mutably references the inner value.
   #[inline]
   pub const fn as_mut(&mut self,) -> Curry6<&mut F,> { Curry6(&mut self.0,) }
+  /// Allows trailing arguments to be omitted in favour of `defaults`.
+  /// 
+  /// Positions given as [`__`] fall back to `Default::default()`.
+  #[inline]
+  pub fn with_defaults<Args, D,>(self, defaults: D,) -> WithDefaults<F, D, Args,> { WithDefaults::new(self.0, defaults,) }
+  /// Fixes the last argument leaving a function of the leading arguments.
+  #[inline]
+  pub fn apply_last<Z,>(self, z: Z,) -> Curry5<RClosure2<Z, F,>,> { Curry5(RClosure2::new(z, self.0,),) }
+  /// Reports the partial applications and invocations of the function to `tracer`.
+  #[inline]
+  pub const fn traced_with<T,>(self, label: &'static str, tracer: T,) -> Traced<Self, T, 6,> { Traced::new(label, tracer, self,) }
+  /// Reports the partial applications and invocations of the function to the [`DefaultTracer`].
+  #[inline]
+  pub fn traced(self, label: &'static str,) -> Traced<Self, DefaultTracer, 6,> { Traced::new(label, default_tracer(), self,) }
+  /// Calls an [`UnsafeFn`] with all of its arguments.
+  /// 
+  /// # Safety
+  /// 
+  /// The safety requirements of the inner function must be upheld.
+  #[inline]
+  pub unsafe fn call_unsafe<A, B, C, D, E, G,>(&self, a: A, b: B, c: C, d: D, e: E, g: G,) -> F::Output
+    where F: UnsafeFn<(A, B, C, D, E, G,)>, {
+    self.0.call_unsafe((a, b, c, d, e, g,),)
+  }
+  /// Asserts that the inner [`UnsafeFn`] is safe to call allowing it to be partially applied.
+  /// 
+  /// # Safety
+  /// 
+  /// Calling the inner function must be safe for every argument it accepts.
+  #[inline]
+  pub unsafe fn assume_safe(self,) -> Curry6<AssumeSafe<F,>,> { Curry6(AssumeSafe::new(self.0,),) }
+}
+
+impl<G,> Curry6<G,> {
+  /// Binds all of the arguments without calling the inner value.
+  #[inline]
+  pub fn defer<A, B, C, D, E, F,>(self, a: A, b: B, c: C, d: D, e: E, f: F,) -> Thunk<G, (A, B, C, D, E, F,),> { Thunk::new((a, b, c, d, e, f,), self.0,) }
 }
 
 impl<F,> Curry6<&'_ F,>
@@ -169,8 +206,43 @@ impl<A, B, C, D, E, F, G,> Fn<(A, B, C, D, E, F,)> for Curry6<G,>
   extern "rust-call" fn call(&self, args: (A, B, C, D, E, F,),) -> Self::Output { self.0.call(args,) }
 }
 
+impl<F, G,> ApplyRight<(F,)> for Curry6<G,> {
+  type Output = Curry5<RClosure2<F, G,>,>;
+
+  #[inline]
+  fn apply_right(self, (f,): (F,),) -> Self::Output { Curry5(RClosure2::new(f, self.0,),) }
+}
+
+impl<E, F, G,> ApplyRight<(E, F,)> for Curry6<G,> {
+  type Output = Curry4<RClosure3<E, F, G,>,>;
+
+  #[inline]
+  fn apply_right(self, (e, f,): (E, F,),) -> Self::Output { Curry4(RClosure3::new(e, f, self.0,),) }
+}
+
+impl<D, E, F, G,> ApplyRight<(D, E, F,)> for Curry6<G,> {
+  type Output = Curry3<RClosure4<D, E, F, G,>,>;
+
+  #[inline]
+  fn apply_right(self, (d, e, f,): (D, E, F,),) -> Self::Output { Curry3(RClosure4::new(d, e, f, self.0,),) }
+}
+
+impl<C, D, E, F, G,> ApplyRight<(C, D, E, F,)> for Curry6<G,> {
+  type Output = Curry2<RClosure5<C, D, E, F, G,>,>;
+
+  #[inline]
+  fn apply_right(self, (c, d, e, f,): (C, D, E, F,),) -> Self::Output { Curry2(RClosure5::new(c, d, e, f, self.0,),) }
+}
+
+impl<B, C, D, E, F, G,> ApplyRight<(B, C, D, E, F,)> for Curry6<G,> {
+  type Output = RClosure6<B, C, D, E, F, G,>;
+
+  #[inline]
+  fn apply_right(self, (b, c, d, e, f,): (B, C, D, E, F,),) -> Self::Output { RClosure6::new(b, c, d, e, f, self.0,) }
+}
+
 impl<T, U,> CoerceUnsized<Curry6<U,>> for Curry6<T,>
-  where T: CoerceUnsized<U> + ?Sized,
+  where T: CoerceUnsized<U>,
     U: ?Sized, {}
 
 /// A closure making a function of arity 6 a unary function.
@@ -181,7 +253,7 @@ impl<T, U,> CoerceUnsized<Curry6<U,>> for Curry6<T,>
 /// let f: Closure6<_, _, _, _, _, _> = Curry6(|a, b, c, d, e, f| a + b + c + d + e + f)(1, 2, 3, 4, 5);
 /// assert_eq!(f(6), 21);
 /// ```
-#[derive(PartialEq, Eq, Clone, Copy, Default, Debug,)]
+#[derive(PartialEq, Eq, Hash, Clone, Copy, Default, Debug,)]
 pub struct Closure6<A, B, C, D, E, F,>
   where F: ?Sized, {
   /// The stored `A` parameter.
@@ -237,7 +309,7 @@ impl<A, B, C, D, E, F, G,> Fn<(F,)> for Closure6<A, B, C, D, E, G,>
 }
 
 impl<A, B, C, D, E, T, U,> CoerceUnsized<Closure6<A, B, C, D, E, U,>> for Closure6<A, B, C, D, E, T,>
-  where T: CoerceUnsized<U> + ?Sized,
+  where T: CoerceUnsized<U>,
     U: ?Sized, {}
 
 #[allow(unused,)]
@@ -0,0 +1,179 @@
+//! Author --- DMorgan  
+//! Last Moddified --- 2026-10-19
+
+use core::{fmt, marker::PhantomData, ops::CoerceUnsized,};
+
+/// A placeholder default argument which falls back to `Default::default()`.
+/// 
+/// `__` deliberately does not implement `Default` so that it can be told apart from a stored
+/// default value.
+#[allow(non_camel_case_types,)]
+#[derive(PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Copy, Debug,)]
+pub struct __;
+
+/// A value which can produce a default argument of type `T`.
+pub trait DefaultArg<T,> {
+  /// Produces the default argument.
+  fn default_arg(&self,) -> T;
+}
+
+impl<T,> DefaultArg<T,> for T
+  where T: Clone, {
+  #[inline]
+  fn default_arg(&self,) -> T { self.clone() }
+}
+
+impl<T,> DefaultArg<T,> for __
+  where T: Default, {
+  #[inline]
+  fn default_arg(&self,) -> T { T::default() }
+}
+
+/// A function whose trailing arguments may be omitted in favour of stored defaults.
+/// 
+/// Any number of trailing arguments may be omitted, the call is always made immediately.
+/// 
+/// ```rust
+/// use curry::*;
+/// 
+/// let f = Curry4(|a: i32, b: i32, c: i32, d: &str| a + b + c + d.len() as i32).with_defaults((__, __, 10, "x",),);
+/// assert_eq!(f(1, 2, 3, "abc"), 9);
+/// assert_eq!(f(1, 2, 3), 7);
+/// assert_eq!(f(1, 2), 14);
+/// assert_eq!(f(1), 12);
+/// assert_eq!(f(), 11);
+/// ```
+pub struct WithDefaults<F, D, Args,>
+  where F: ?Sized, {
+  /// The default arguments.
+  pub defaults: D,
+  /// The types of the arguments of `func`.
+  args: PhantomData<fn(Args,)>,
+  /// The function being wrapped.
+  pub func: F,
+}
+
+impl<F, D, Args,> WithDefaults<F, D, Args,> {
+  /// Constructs a new `WithDefaults` from `func` and `defaults`.
+  #[inline]
+  pub const fn new(func: F, defaults: D,) -> Self { WithDefaults { defaults, args: PhantomData, func, } }
+}
+
+impl<F, D, Args,> PartialEq for WithDefaults<F, D, Args,>
+  where F: PartialEq + ?Sized,
+    D: PartialEq, {
+  #[inline]
+  fn eq(&self, rhs: &Self,) -> bool { self.defaults == rhs.defaults && self.func == rhs.func }
+}
+
+impl<F, D, Args,> Eq for WithDefaults<F, D, Args,>
+  where F: Eq + ?Sized,
+    D: Eq, {}
+
+impl<F, D, Args,> Clone for WithDefaults<F, D, Args,>
+  where F: Clone,
+    D: Clone, {
+  #[inline]
+  fn clone(&self,) -> Self { WithDefaults::new(self.func.clone(), self.defaults.clone(),) }
+}
+
+impl<F, D, Args,> Copy for WithDefaults<F, D, Args,>
+  where F: Copy,
+    D: Copy, {}
+
+impl<F, D, Args,> Default for WithDefaults<F, D, Args,>
+  where F: Default,
+    D: Default, {
+  #[inline]
+  fn default() -> Self { WithDefaults::new(F::default(), D::default(),) }
+}
+
+impl<F, D, Args,> fmt::Debug for WithDefaults<F, D, Args,>
+  where F: fmt::Debug + ?Sized,
+    D: fmt::Debug, {
+  fn fmt(&self, fmt: &mut fmt::Formatter,) -> fmt::Result {
+    fmt.debug_struct("WithDefaults",)
+      .field("defaults", &self.defaults,)
+      .field("func", &&self.func,)
+      .finish()
+  }
+}
+
+/// Implements the `Fn*` traits for `WithDefaults` called with a single count of arguments.
+macro_rules! impl_with_defaults {
+  (($($arg_tp:ident $arg:ident $arg_dtp:ident,)*) ($($def_tp:ident $def:ident $def_dtp:ident,)*)) => {
+    impl<$($arg_tp, $arg_dtp,)* $($def_tp, $def_dtp,)* X,> FnOnce<($($arg_tp,)*)> for WithDefaults<X, ($($arg_dtp,)* $($def_dtp,)*), ($($arg_tp,)* $($def_tp,)*),>
+      where $($def_dtp: DefaultArg<$def_tp,>,)*
+        X: FnOnce<($($arg_tp,)* $($def_tp,)*)>, {
+      type Output = X::Output;
+
+      #[inline]
+      extern "rust-call" fn call_once(self, ($($arg,)*): ($($arg_tp,)*),) -> Self::Output {
+        let (.., $($def,)*) = &self.defaults;
+
+        (self.func)($($arg,)* $($def.default_arg(),)*)
+      }
+    }
+
+    impl<$($arg_tp, $arg_dtp,)* $($def_tp, $def_dtp,)* X,> FnMut<($($arg_tp,)*)> for WithDefaults<X, ($($arg_dtp,)* $($def_dtp,)*), ($($arg_tp,)* $($def_tp,)*),>
+      where $($def_dtp: DefaultArg<$def_tp,>,)*
+        X: FnMut<($($arg_tp,)* $($def_tp,)*)>, {
+      #[inline]
+      extern "rust-call" fn call_mut(&mut self, ($($arg,)*): ($($arg_tp,)*),) -> Self::Output {
+        let (.., $($def,)*) = &self.defaults;
+
+        (self.func)($($arg,)* $($def.default_arg(),)*)
+      }
+    }
+
+    impl<$($arg_tp, $arg_dtp,)* $($def_tp, $def_dtp,)* X,> Fn<($($arg_tp,)*)> for WithDefaults<X, ($($arg_dtp,)* $($def_dtp,)*), ($($arg_tp,)* $($def_tp,)*),>
+      where $($def_dtp: DefaultArg<$def_tp,>,)*
+        X: Fn<($($arg_tp,)* $($def_tp,)*)>, {
+      #[inline]
+      extern "rust-call" fn call(&self, ($($arg,)*): ($($arg_tp,)*),) -> Self::Output {
+        let (.., $($def,)*) = &self.defaults;
+
+        (self.func)($($arg,)* $($def.default_arg(),)*)
+      }
+    }
+  };
+}
+
+impl_with_defaults!(() (A a Da, B b Db,));
+impl_with_defaults!((A a Da,) (B b Db,));
+impl_with_defaults!((A a Da, B b Db,) ());
+
+impl_with_defaults!(() (A a Da, B b Db, C c Dc,));
+impl_with_defaults!((A a Da,) (B b Db, C c Dc,));
+impl_with_defaults!((A a Da, B b Db,) (C c Dc,));
+impl_with_defaults!((A a Da, B b Db, C c Dc,) ());
+
+impl_with_defaults!(() (A a Da, B b Db, C c Dc, D d Dd,));
+impl_with_defaults!((A a Da,) (B b Db, C c Dc, D d Dd,));
+impl_with_defaults!((A a Da, B b Db,) (C c Dc, D d Dd,));
+impl_with_defaults!((A a Da, B b Db, C c Dc,) (D d Dd,));
+impl_with_defaults!((A a Da, B b Db, C c Dc, D d Dd,) ());
+
+impl_with_defaults!(() (A a Da, B b Db, C c Dc, D d Dd, E e De,));
+impl_with_defaults!((A a Da,) (B b Db, C c Dc, D d Dd, E e De,));
+impl_with_defaults!((A a Da, B b Db,) (C c Dc, D d Dd, E e De,));
+impl_with_defaults!((A a Da, B b Db, C c Dc,) (D d Dd, E e De,));
+impl_with_defaults!((A a Da, B b Db, C c Dc, D d Dd,) (E e De,));
+impl_with_defaults!((A a Da, B b Db, C c Dc, D d Dd, E e De,) ());
+
+impl_with_defaults!(() (A a Da, B b Db, C c Dc, D d Dd, E e De, F f Df,));
+impl_with_defaults!((A a Da,) (B b Db, C c Dc, D d Dd, E e De, F f Df,));
+impl_with_defaults!((A a Da, B b Db,) (C c Dc, D d Dd, E e De, F f Df,));
+impl_with_defaults!((A a Da, B b Db, C c Dc,) (D d Dd, E e De, F f Df,));
+impl_with_defaults!((A a Da, B b Db, C c Dc, D d Dd,) (E e De, F f Df,));
+impl_with_defaults!((A a Da, B b Db, C c Dc, D d Dd, E e De,) (F f Df,));
+impl_with_defaults!((A a Da, B b Db, C c Dc, D d Dd, E e De, F f Df,) ());
+
+impl<D, Args, T, U,> CoerceUnsized<WithDefaults<U, D, Args,>> for WithDefaults<T, D, Args,>
+  where T: CoerceUnsized<U>,
+    U: ?Sized, {}
+
+#[allow(unused,)]
+fn _assert_coerce_unsized(a: WithDefaults<&i32, (), (),>,) {
+  let _: WithDefaults<&dyn Send, (), (),> = a;
+}
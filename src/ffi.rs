@@ -0,0 +1,150 @@
+//! Exports functions as C callbacks which take a `void *userdata` before their arguments.
+//! 
+//! Author --- DMorgan  
+//! Last Moddified --- 2026-10-19
+
+use alloc::boxed::Box;
+use core::{ffi::c_void, fmt, ptr::NonNull,};
+
+/// A boxed function exported as a C callback.
+/// 
+/// `func` must be called with `userdata` as its first argument and may be called from several
+/// threads at once. `drop` must be called with `userdata` exactly once, after every call to
+/// `func` has returned; after which neither may be used again. A panic which escapes the function
+/// aborts the process.
+/// 
+/// ```rust
+/// use curry::{*, ffi::*,};
+/// use core::ffi::c_void;
+/// 
+/// // A C library function which accepts a callback.
+/// extern "C" fn call_twice(
+///   func: unsafe extern "C" fn(*mut c_void, i32,) -> i32,
+///   userdata: *mut c_void,
+///   drop: unsafe extern "C" fn(*mut c_void,),
+/// ) -> i32 {
+///   unsafe {
+///     let res = func(userdata, 1,) + func(userdata, 2,);
+/// 
+///     drop(userdata,);
+///     res
+///   }
+/// }
+/// 
+/// let callback = Curry2(|a: i32, b: i32| a * b)(10).into_callback();
+/// assert_eq!(call_twice(callback.func, callback.userdata, callback.drop,), 30);
+/// ```
+/// 
+/// Arguments and results which can not cross the C ABI are rejected at compile time.
+/// 
+/// ```rust,compile_fail
+/// use curry::{*, ffi::*,};
+/// 
+/// let callback = Curry2(|a: i32, b: String| a as usize + b.len())(10).into_callback();
+/// ```
+/// 
+/// As are functions which can not be shared between threads.
+/// 
+/// ```rust,compile_fail
+/// use curry::{*, ffi::*,};
+/// use core::cell::Cell;
+/// 
+/// let count = Cell::new(0,);
+/// let callback = Curry2(move |a: i32, b: i32| { count.set(count.get() + 1,); a + b })(10).into_callback();
+/// ```
+#[must_use = "the boxed function is leaked unless `drop` or `free` is called"]
+pub struct Callback<T,> {
+  /// The trampoline which calls the function stored in `userdata`.
+  pub func: T,
+  /// The boxed function.
+  pub userdata: *mut c_void,
+  /// Drops the boxed function.
+  pub drop: unsafe extern "C" fn(*mut c_void,),
+}
+
+impl<T,> Callback<T,> {
+  /// Drops the boxed function without handing it to C.
+  /// 
+  /// # Safety
+  /// 
+  /// `drop` must not already have been called with `userdata`.
+  #[inline]
+  pub unsafe fn free(self,) { (self.drop)(self.userdata,) }
+}
+
+impl<T,> fmt::Debug for Callback<T,> {
+  fn fmt(&self, fmt: &mut fmt::Formatter,) -> fmt::Result {
+    fmt.debug_struct("Callback",).field("userdata", &self.userdata,).finish()
+  }
+}
+
+/// A type which can be passed to and returned from C by value.
+/// 
+/// # Safety
+/// 
+/// The type must have a stable C compatible layout, such as a `#[repr(C)]` struct of `FfiSafe`
+/// fields, and every bit pattern C may produce for it must be a valid value.
+pub unsafe trait FfiSafe {}
+
+/// Implements `FfiSafe` for types with a C equivalent.
+macro_rules! impl_ffi_safe {
+  ($($tp:ty,)*) => { $(unsafe impl FfiSafe for $tp {})* };
+}
+
+impl_ffi_safe!((), i8, i16, i32, i64, isize, u8, u16, u32, u64, usize, f32, f64,);
+
+unsafe impl<T,> FfiSafe for *const T {}
+unsafe impl<T,> FfiSafe for *mut T {}
+unsafe impl<T,> FfiSafe for Option<NonNull<T,>> {}
+
+/// A function which can be exported as a C callback taking `Args`.
+/// 
+/// The arguments and result must be [`FfiSafe`]. The function must be `Send` and `Sync` as C
+/// libraries commonly invoke callbacks from their own threads, possibly concurrently, and so it
+/// is only called through a shared reference.
+pub trait IntoCallback<Args,>: Sized {
+  /// The type of the C callback.
+  type Func: Copy;
+
+  /// Boxes the function and exports it as a C callback.
+  fn into_callback(self,) -> Callback<Self::Func,>;
+}
+
+/// Drops the function of type `F` stored in `userdata`.
+unsafe extern "C" fn drop_userdata<F,>(userdata: *mut c_void,) {
+  drop(Box::from_raw(userdata as *mut F,),)
+}
+
+/// Implements `IntoCallback` for a single arity.
+macro_rules! impl_into_callback {
+  ($trampoline:ident => $($arg_tp:ident $arg:ident,)*) => {
+    /// Calls the function of type `F` stored in `userdata`.
+    unsafe extern "C" fn $trampoline<F, $($arg_tp,)* R,>(userdata: *mut c_void, $($arg: $arg_tp,)*) -> R
+      where F: Fn<($($arg_tp,)*), Output = R>, {
+      (*(userdata as *const F)).call(($($arg,)*),)
+    }
+
+    impl<F, $($arg_tp,)* R,> IntoCallback<($($arg_tp,)*)> for F
+      where F: Fn<($($arg_tp,)*), Output = R> + Send + Sync,
+        $($arg_tp: FfiSafe,)*
+        R: FfiSafe, {
+      type Func = unsafe extern "C" fn(*mut c_void, $($arg_tp,)*) -> R;
+
+      fn into_callback(self,) -> Callback<Self::Func,> {
+        Callback {
+          func: $trampoline::<F, $($arg_tp,)* R,>,
+          userdata: Box::into_raw(Box::new(self,),) as *mut c_void,
+          drop: drop_userdata::<F,>,
+        }
+      }
+    }
+  };
+}
+
+impl_into_callback!(trampoline0 =>);
+impl_into_callback!(trampoline1 => A a,);
+impl_into_callback!(trampoline2 => A a, B b,);
+impl_into_callback!(trampoline3 => A a, B b, C c,);
+impl_into_callback!(trampoline4 => A a, B b, C c, D d,);
+impl_into_callback!(trampoline5 => A a, B b, C c, D d, E e,);
+impl_into_callback!(trampoline6 => A a, B b, C c, D d, E e, G g,);
@@ -1,5 +1,5 @@
 //! Author --- DMorgan  
-//! Last Moddified --- 2021-03-03
+//! Last Moddified --- 2026-10-19
 
 /// A convenience macro for implementing `Fn*` traits for types.
 /// 
@@ -7,7 +7,8 @@
 /// 
 /// # Examples
 /// 
-/// ```rust
+#[cfg_attr(nightly, doc = "```rust",)]
+#[cfg_attr(not(nightly,), doc = "```ignore",)]
 /// use curry::*;
 /// 
 /// struct Unit;
@@ -28,28 +29,429 @@
 /// assert_eq!((Generics)('c'), 42);
 /// assert_eq!((Generics)(&1, 2, 3), (&1, 3, 2));
 /// ```
+/// 
+/// Generic parameters may have bounds, const generics are supported and a `where` clause may
+/// follow the implementing type.
+/// 
+#[cfg_attr(nightly, doc = "```rust",)]
+#[cfg_attr(not(nightly,), doc = "```ignore",)]
+/// use curry::*;
+/// use core::{fmt::Display, ops::Add,};
+/// 
+/// struct Fmt;
+/// 
+/// impl_fn!(<T: Display> Fn(T,) for Fmt => (x) -> usize { format!("{}", x).len() });
+/// 
+/// struct Len;
+/// 
+/// impl_fn!(<const N: usize> Fn([u8; N],) for Len => (_) -> usize { N });
+/// 
+/// struct Sum<T>(T);
+/// 
+/// impl_fn!(<T> Fn(T,) for Sum<T> where T: Add<Output = T> + Copy => (x) -> T { x + x });
+/// 
+/// assert_eq!((Fmt)(1234), 4);
+/// assert_eq!((Len)([0; 3]), 3);
+/// assert_eq!((Sum(0))(21), 42);
+/// ```
+/// 
+/// To use the receiver in the body it is named before the parameters as `|self, (params)|`.
+/// 
+#[cfg_attr(nightly, doc = "```rust",)]
+#[cfg_attr(not(nightly,), doc = "```ignore",)]
+/// use curry::*;
+/// 
+/// struct Scale { factor: i32, }
+/// 
+/// impl_fn!(Fn(i32,) for Scale => |self, (x)| -> i32 { x * self.factor });
+/// 
+/// let scale = Scale { factor: 3, };
+/// assert_eq!(scale(2), 6);
+/// assert_eq!(Curry2(|a, b| a + b)(1)(scale(4)), 13);
+/// ```
+/// 
+/// Several overloads for the same type can be implemented in one block and, when prefixed with
+/// `curry`, each overload can also be partially applied as if it was wrapped in the matching
+/// `CurryN`; this requires the type to be `Clone`.
+/// 
+#[cfg_attr(nightly, doc = "```rust",)]
+#[cfg_attr(not(nightly,), doc = "```ignore",)]
+/// use curry::*;
+/// 
+/// #[derive(Clone, Copy,)]
+/// struct Overloaded;
+/// 
+/// impl_fn!(curry for Overloaded {
+///   Fn(char,) => (_) -> i32 { 42 },
+///   Fn(i32, i32, i32,) => (a, b, c) -> i32 { a + b + c },
+///   <'a, A: Clone> Fn(&'a A, char,) where A: 'a => (a, c) -> (A, char) { (a.clone(), c) },
+/// });
+/// 
+/// assert_eq!(Overloaded('c'), 42);
+/// assert_eq!(Overloaded(1, 2, 3), 6);
+/// assert_eq!(Overloaded(1, 2)(3), 6);
+/// assert_eq!(Overloaded(&1, 'c'), (1, 'c'));
+/// ```
+/// 
+/// `FnMut` and `FnOnce` only implement the traits which they imply; in the body `self` is then
+/// `&mut self` for `FnMut` and `self` for `FnOnce`.
+/// 
+#[cfg_attr(nightly, doc = "```rust",)]
+#[cfg_attr(not(nightly,), doc = "```ignore",)]
+/// use curry::*;
+/// 
+/// struct Counter(i32);
+/// 
+/// impl_fn!(FnMut(i32,) for Counter => |self, (x)| -> i32 { self.0 += x; self.0 });
+/// 
+/// struct Token(&'static str);
+/// 
+/// impl_fn!(FnOnce() for Token => |self, ()| -> &'static str { self.0 });
+/// impl_fn!(FnOnce(char,) for Token => |mut self, (c)| -> usize { self.0 = ""; c.len_utf8() });
+/// 
+/// let mut counter = Counter(0);
+/// assert_eq!(counter(1), 1);
+/// assert_eq!(counter(2), 3);
+/// assert_eq!(Token("token")(), "token");
+/// assert_eq!(Token("token")('c'), 1);
+/// ```
+/// 
+/// On a stable toolchain the [`Call`](crate::Call), [`CallMut`](crate::CallMut) and
+/// [`CallOnce`](crate::CallOnce) traits are implemented instead of the `Fn*` traits and `curry`
+/// is unavailable.
+/// 
+/// When prefixed with `call` a signature without generic parameters also gets an inherent `call`
+/// method taking the arguments in place of the call syntax, on either toolchain; as with
+/// `#[fn_object(call)]` only one signature per type can request it.
+/// 
+#[cfg_attr(nightly, doc = "```ignore",)]
+#[cfg_attr(not(nightly,), doc = "```rust",)]
+/// use curry::*;
+/// 
+/// struct Add;
+/// 
+/// impl_fn!(call Fn(i32, i32,) for Add => (a, b) -> i32 { a + b });
+/// impl_fn!(Fn(i32,) for Add => (a) -> i32 { a });
+/// 
+/// struct Overloaded;
+/// 
+/// impl_fn!(for Overloaded {
+///   Fn(char,) => (_) -> i32 { 42 },
+///   <A> Fn(A, A,) => (_, _) -> i32 { 2 },
+/// });
+/// 
+/// assert_eq!(Add.call(1, 2), 3);
+/// assert_eq!(Call::call(&Add, (1, 2,)), 3);
+/// assert_eq!(Call::call(&Add, (1,)), 1);
+/// assert_eq!(Call::call(&Overloaded, ('c',)), 42);
+/// assert_eq!(Call::call(&Overloaded, (1, 2,)), 2);
+/// ```
 #[macro_export(local_inner_macros,)]
 macro_rules! impl_fn {
-  ($(<$($life:lifetime,)* $($gen:ident),+ $(,)?>)? Fn ($($arg_tp:ty,)*) for $type:ty => ($($arg:pat),* $(,)?) -> $ret:ty $body:block) => {
-    impl $(<$($life,)* $($gen),+>)? FnOnce<($($arg_tp,)*)> for $type {
+  (@overloads $curry:tt $type:tt [$($arm:tt)*] { $($body:tt)* } , $($rest:tt)*) => {
+    impl_fn!(@overload $curry $type $($arm)* { $($body)* });
+    impl_fn!(@overloads $curry $type [] $($rest)*);
+  };
+  (@overloads $curry:tt $type:tt [$($arm:tt)*] { $($body:tt)* }) => { impl_fn!(@overload $curry $type $($arm)* { $($body)* }); };
+  (@overloads $curry:tt $type:tt []) => {};
+  (@overloads $curry:tt $type:tt [$($arm:tt)*] $tok:tt $($rest:tt)*) => { impl_fn!(@overloads $curry $type [$($arm)* $tok] $($rest)*); };
+  (@overload $curry:tt $type:tt < $($rest:tt)*) => { impl_fn!(@overload_generics $curry $type [] $($rest)*); };
+  (@overload $curry:tt [$type:ty] $fn_trait:ident ($($arg_tps:tt)*) $($rest:tt)*) => {
+    impl_fn!(@signature $curry [] $fn_trait ($($arg_tps)*) for $type $($rest)*);
+  };
+  (@overload_generics $curry:tt [$type:ty] [$($gen:tt)*] > $fn_trait:ident ($($arg_tps:tt)*) $($rest:tt)*) => {
+    impl_fn!(@signature $curry [$($gen)*] $fn_trait ($($arg_tps)*) for $type $($rest)*);
+  };
+  (@overload_generics $curry:tt [$type:ty] [$($gen:tt)*] >> $fn_trait:ident ($($arg_tps:tt)*) $($rest:tt)*) => {
+    impl_fn!(@signature $curry [$($gen)* >] $fn_trait ($($arg_tps)*) for $type $($rest)*);
+  };
+  (@overload_generics $curry:tt $type:tt [$($gen:tt)*] $tok:tt $($rest:tt)*) => {
+    impl_fn!(@overload_generics $curry $type [$($gen)* $tok] $($rest)*);
+  };
+  (@generics $curry:tt [$($gen:tt)*] > $fn_trait:ident $($rest:tt)*) => { impl_fn!(@signature $curry [$($gen)*] $fn_trait $($rest)*); };
+  (@generics $curry:tt [$($gen:tt)*] >> $fn_trait:ident $($rest:tt)*) => { impl_fn!(@signature $curry [$($gen)* >] $fn_trait $($rest)*); };
+  (@generics $curry:tt [$($gen:tt)*] $tok:tt $($rest:tt)*) => { impl_fn!(@generics $curry [$($gen)* $tok] $($rest)*); };
+  (@signature $curry:tt $gen:tt $fn_trait:ident ($($arg_tp:ty,)*) for $type:ty where $($rest:tt)*) => {
+    impl_fn!(@where $curry $fn_trait $gen [$($arg_tp,)*] [$type] [] $($rest)*);
+  };
+  (@signature $curry:tt $gen:tt $fn_trait:ident ($($arg_tp:ty,)*) for $type:ty => $($rest:tt)*) => {
+    impl_fn!(@impl $curry $fn_trait $gen [$($arg_tp,)*] [$type] [] => $($rest)*);
+  };
+  (@where $curry:tt $fn_trait:ident $gen:tt $arg_tps:tt $type:tt [$($bound:tt)*] => $($rest:tt)*) => {
+    impl_fn!(@impl $curry $fn_trait $gen $arg_tps $type [$($bound)*] => $($rest)*);
+  };
+  (@where $curry:tt $fn_trait:ident $gen:tt $arg_tps:tt $type:tt [$($bound:tt)*] $tok:tt $($rest:tt)*) => {
+    impl_fn!(@where $curry $fn_trait $gen $arg_tps $type [$($bound)* $tok] $($rest)*);
+  };
+  (@impl $curry:tt $fn_trait:ident $gen:tt $arg_tps:tt $type:tt $bound:tt => ($($arg:pat),* $(,)?) -> $ret:ty $body:block) => {
+    impl_fn!(@impl $curry $fn_trait $gen $arg_tps $type $bound => |self, ($($arg,)*)| -> $ret $body);
+  };
+  (@impl $curry:tt $fn_trait:ident $gen:tt $arg_tps:tt $type:tt $bound:tt => |mut $self_:ident, ($($arg:pat),* $(,)?)| -> $ret:ty $body:block) => {
+    impl_fn_traits!($fn_trait [mut $self_] $gen $arg_tps $type $bound [$($arg,)*] -> $ret $body);
+    impl_fn!(@inherent $curry $gen $fn_trait [mut $self_] $arg_tps $type $bound [$($arg,)*] -> $ret $body);
+    impl_fn!(@curry $curry $gen $bound $type $arg_tps);
+  };
+  (@impl $curry:tt $fn_trait:ident $gen:tt $arg_tps:tt $type:tt $bound:tt => |$self_:ident, ($($arg:pat),* $(,)?)| -> $ret:ty $body:block) => {
+    impl_fn_traits!($fn_trait [$self_] $gen $arg_tps $type $bound [$($arg,)*] -> $ret $body);
+    impl_fn!(@inherent $curry $gen $fn_trait [$self_] $arg_tps $type $bound [$($arg,)*] -> $ret $body);
+    impl_fn!(@curry $curry $gen $bound $type $arg_tps);
+  };
+  (@inherent [call] [] Fn [$self_:ident] [$($arg_tp:ty,)*] [$type:ty] [$($bound:tt)*] [$($arg:pat,)*] -> $ret:ty $body:block) => {
+    impl $type
+      where $($bound)* {
+      /// Calls the function without the call syntax.
+      #[allow(dead_code,)]
+      pub fn call(&$self_, $($arg: $arg_tp,)*) -> $ret $body
+    }
+  };
+  (@inherent [call] [] FnMut [$self_:ident] [$($arg_tp:ty,)*] [$type:ty] [$($bound:tt)*] [$($arg:pat,)*] -> $ret:ty $body:block) => {
+    impl $type
+      where $($bound)* {
+      /// Calls the function without the call syntax.
+      #[allow(dead_code,)]
+      pub fn call(&mut $self_, $($arg: $arg_tp,)*) -> $ret $body
+    }
+  };
+  (@inherent [call] [] FnOnce [mut $self_:ident] [$($arg_tp:ty,)*] [$type:ty] [$($bound:tt)*] [$($arg:pat,)*] -> $ret:ty $body:block) => {
+    impl $type
+      where $($bound)* {
+      /// Calls the function without the call syntax.
+      #[allow(dead_code,)]
+      pub fn call(mut $self_, $($arg: $arg_tp,)*) -> $ret $body
+    }
+  };
+  (@inherent [call] [] FnOnce [$self_:ident] [$($arg_tp:ty,)*] [$type:ty] [$($bound:tt)*] [$($arg:pat,)*] -> $ret:ty $body:block) => {
+    impl $type
+      where $($bound)* {
+      /// Calls the function without the call syntax.
+      #[allow(dead_code,)]
+      pub fn call($self_, $($arg: $arg_tp,)*) -> $ret $body
+    }
+  };
+  (@inherent [call] [$($gen:tt)+] $($rest:tt)*) => {
+    ::core::compile_error!("an inherent `call` method requires a signature without generic parameters",);
+  };
+  (@inherent $($rest:tt)*) => {};
+  (@curry [] $($rest:tt)*) => {};
+  (@curry [call] $($rest:tt)*) => {};
+  (@curry [overload] $($rest:tt)*) => {};
+  (@curry [curry $($overload:ident)?] $gen:tt $bound:tt $type:tt []) => {};
+  (@curry [curry $($overload:ident)?] $gen:tt $bound:tt $type:tt [$a:ty,]) => {};
+  (@curry [curry $($overload:ident)?] $gen:tt $bound:tt $type:tt [$a:ty, $b:ty,]) => {
+    impl_fn_traits!(@partial Curry2 $gen $bound $type [$a,]);
+  };
+  (@curry [curry $($overload:ident)?] $gen:tt $bound:tt $type:tt [$a:ty, $b:ty, $c:ty,]) => {
+    impl_fn_traits!(@partial Curry3 $gen $bound $type [$a,]);
+    impl_fn_traits!(@partial Curry3 $gen $bound $type [$a, $b,]);
+  };
+  (@curry [curry $($overload:ident)?] $gen:tt $bound:tt $type:tt [$a:ty, $b:ty, $c:ty, $d:ty,]) => {
+    impl_fn_traits!(@partial Curry4 $gen $bound $type [$a,]);
+    impl_fn_traits!(@partial Curry4 $gen $bound $type [$a, $b,]);
+    impl_fn_traits!(@partial Curry4 $gen $bound $type [$a, $b, $c,]);
+  };
+  (@curry [curry $($overload:ident)?] $gen:tt $bound:tt $type:tt [$a:ty, $b:ty, $c:ty, $d:ty, $e:ty,]) => {
+    impl_fn_traits!(@partial Curry5 $gen $bound $type [$a,]);
+    impl_fn_traits!(@partial Curry5 $gen $bound $type [$a, $b,]);
+    impl_fn_traits!(@partial Curry5 $gen $bound $type [$a, $b, $c,]);
+    impl_fn_traits!(@partial Curry5 $gen $bound $type [$a, $b, $c, $d,]);
+  };
+  (@curry [curry $($overload:ident)?] $gen:tt $bound:tt $type:tt [$a:ty, $b:ty, $c:ty, $d:ty, $e:ty, $f:ty,]) => {
+    impl_fn_traits!(@partial Curry6 $gen $bound $type [$a,]);
+    impl_fn_traits!(@partial Curry6 $gen $bound $type [$a, $b,]);
+    impl_fn_traits!(@partial Curry6 $gen $bound $type [$a, $b, $c,]);
+    impl_fn_traits!(@partial Curry6 $gen $bound $type [$a, $b, $c, $d,]);
+    impl_fn_traits!(@partial Curry6 $gen $bound $type [$a, $b, $c, $d, $e,]);
+  };
+  (curry for $type:ty { $($arms:tt)* }) => { impl_fn!(@overloads [curry overload] [$type] [] $($arms)*); };
+  (for $type:ty { $($arms:tt)* }) => { impl_fn!(@overloads [overload] [$type] [] $($arms)*); };
+  (call < $($rest:tt)*) => { impl_fn!(@generics [call] [] $($rest)*); };
+  (call $fn_trait:ident $($rest:tt)*) => { impl_fn!(@signature [call] [] $fn_trait $($rest)*); };
+  (curry < $($rest:tt)*) => { impl_fn!(@generics [curry] [] $($rest)*); };
+  (curry $fn_trait:ident $($rest:tt)*) => { impl_fn!(@signature [curry] [] $fn_trait $($rest)*); };
+  (< $($rest:tt)*) => { impl_fn!(@generics [] [] $($rest)*); };
+  ($fn_trait:ident $($rest:tt)*) => { impl_fn!(@signature [] [] $fn_trait $($rest)*); };
+}
+
+/// Defines a named curried function as a zero sized type.
+/// 
+/// The function can be called with all of its arguments or partially applied exactly like the
+/// equivalent `CurryN`; unlike a wrapped closure the item is a `const` and can be named in types.
+/// Lifetimes used by the argument types must be declared on the function.
+/// 
+/// # Examples
+/// 
+#[cfg_attr(nightly, doc = "```rust",)]
+#[cfg_attr(not(nightly,), doc = "```ignore",)]
+/// use curry::*;
+/// 
+/// curried_fn! {
+///   /// Sums three numbers.
+///   pub fn add(a: i32, b: i32, c: i32,) -> i32 { a + b + c }
+/// }
+/// 
+/// curried_fn! {
+///   fn prefix<'a>(p: &'a str, s: &'a str) -> bool { s.starts_with(p) }
+/// }
+/// 
+/// const ADD: add = add;
+/// 
+/// assert_eq!(add(1, 2, 3), 6);
+/// assert_eq!(add(1, 2)(3), 6);
+/// assert_eq!(ADD(1)(2)(3), 6);
+/// assert!(prefix("cu")("curry"));
+/// ```
+#[macro_export(local_inner_macros,)]
+macro_rules! curried_fn {
+  ($(#[$meta:meta])* $vis:vis fn $name:ident $(<$($lt:lifetime),+ $(,)?>)? ($($arg:ident : $arg_tp:ty),* $(,)?) { $($body:tt)* }) => {
+    curried_fn!($(#[$meta])* $vis fn $name $(<$($lt,)+>)? ($($arg : $arg_tp,)*) -> () { $($body)* });
+  };
+  ($(#[$meta:meta])* $vis:vis fn $name:ident $(<$($lt:lifetime),+ $(,)?>)? ($($arg:ident : $arg_tp:ty),* $(,)?) -> $ret:ty { $($body:tt)* }) => {
+    $(#[$meta])*
+    #[allow(non_camel_case_types,)]
+    #[derive(PartialEq, Eq, Clone, Copy, Default, Debug,)]
+    $vis struct $name;
+
+    impl_fn!(curry for $name { $(<$($lt,)+>)? Fn($($arg_tp,)*) => ($($arg,)*) -> $ret { $($body)* } });
+  };
+}
+
+/// Implements the traits generated by [`impl_fn`](crate::impl_fn).
+#[doc(hidden,)]
+#[cfg(nightly,)]
+#[macro_export]
+macro_rules! impl_fn_traits {
+  (Fn [$self_:ident] [$($gen:tt)*] [$($arg_tp:ty,)*] [$type:ty] [$($bound:tt)*] [$($arg:pat,)*] -> $ret:ty $body:block) => {
+    impl<$($gen)*> FnOnce<($($arg_tp,)*)> for $type
+      where $($bound)* {
       type Output = $ret;
 
       #[inline(always,)]
       extern "rust-call" fn call_once(self, args : ($($arg_tp,)*),) -> Self::Output { Fn::call(&self, args,) }
     }
 
-    impl $(<$($life,)* $($gen),+>)? FnMut<($($arg_tp,)*)> for $type {
+    impl<$($gen)*> FnMut<($($arg_tp,)*)> for $type
+      where $($bound)* {
       #[inline(always,)]
       extern "rust-call" fn call_mut(&mut self, args : ($($arg_tp,)*),) -> Self::Output { Fn::call(self, args,) }
     }
 
-    impl $(<$($life,)* $($gen),+>)? Fn<($($arg_tp,)*)> for $type {
-      extern "rust-call" fn call(&self, ($($arg,)*) : ($($arg_tp,)*),) -> Self::Output $body
+    impl<$($gen)*> Fn<($($arg_tp,)*)> for $type
+      where $($bound)* {
+      extern "rust-call" fn call(&$self_, ($($arg,)*) : ($($arg_tp,)*),) -> Self::Output $body
+    }
+  };
+  (FnMut [$self_:ident] [$($gen:tt)*] [$($arg_tp:ty,)*] [$type:ty] [$($bound:tt)*] [$($arg:pat,)*] -> $ret:ty $body:block) => {
+    impl<$($gen)*> FnOnce<($($arg_tp,)*)> for $type
+      where $($bound)* {
+      type Output = $ret;
+
+      #[inline(always,)]
+      extern "rust-call" fn call_once(mut self, args : ($($arg_tp,)*),) -> Self::Output { FnMut::call_mut(&mut self, args,) }
+    }
+
+    impl<$($gen)*> FnMut<($($arg_tp,)*)> for $type
+      where $($bound)* {
+      extern "rust-call" fn call_mut(&mut $self_, ($($arg,)*) : ($($arg_tp,)*),) -> Self::Output $body
+    }
+  };
+  (FnOnce [mut $self_:ident] [$($gen:tt)*] [$($arg_tp:ty,)*] [$type:ty] [$($bound:tt)*] [$($arg:pat,)*] -> $ret:ty $body:block) => {
+    impl<$($gen)*> FnOnce<($($arg_tp,)*)> for $type
+      where $($bound)* {
+      type Output = $ret;
+
+      extern "rust-call" fn call_once(mut $self_, ($($arg,)*) : ($($arg_tp,)*),) -> Self::Output $body
+    }
+  };
+  (FnOnce [$self_:ident] [$($gen:tt)*] [$($arg_tp:ty,)*] [$type:ty] [$($bound:tt)*] [$($arg:pat,)*] -> $ret:ty $body:block) => {
+    impl<$($gen)*> FnOnce<($($arg_tp,)*)> for $type
+      where $($bound)* {
+      type Output = $ret;
+
+      extern "rust-call" fn call_once($self_, ($($arg,)*) : ($($arg_tp,)*),) -> Self::Output $body
+    }
+  };
+  (@partial $curry:ident [$($gen:tt)*] [$($bound:tt)*] [$type:ty] [$($arg_tp:ty,)*]) => {
+    impl<$($gen)*> FnOnce<($($arg_tp,)*)> for $type
+      where $crate::$curry<$type>: FnOnce<($($arg_tp,)*)>, $($bound)* {
+      type Output = <$crate::$curry<$type> as FnOnce<($($arg_tp,)*)>>::Output;
+
+      #[inline(always,)]
+      extern "rust-call" fn call_once(self, args : ($($arg_tp,)*),) -> Self::Output { FnOnce::call_once($crate::$curry(self,), args,) }
+    }
+
+    impl<$($gen)*> FnMut<($($arg_tp,)*)> for $type
+      where $type: Clone, $crate::$curry<$type>: FnOnce<($($arg_tp,)*)>, $($bound)* {
+      #[inline(always,)]
+      extern "rust-call" fn call_mut(&mut self, args : ($($arg_tp,)*),) -> Self::Output { FnOnce::call_once($crate::$curry(Clone::clone(self,),), args,) }
+    }
+
+    impl<$($gen)*> Fn<($($arg_tp,)*)> for $type
+      where $type: Clone, $crate::$curry<$type>: FnOnce<($($arg_tp,)*)>, $($bound)* {
+      #[inline(always,)]
+      extern "rust-call" fn call(&self, args : ($($arg_tp,)*),) -> Self::Output { FnOnce::call_once($crate::$curry(Clone::clone(self,),), args,) }
     }
   };
 }
 
-#[cfg(test,)]
+/// Implements the traits generated by [`impl_fn`](crate::impl_fn).
+#[doc(hidden,)]
+#[cfg(not(nightly,),)]
+#[macro_export]
+macro_rules! impl_fn_traits {
+  (Fn [$self_:ident] [$($gen:tt)*] [$($arg_tp:ty,)*] [$type:ty] [$($bound:tt)*] [$($arg:pat,)*] -> $ret:ty $body:block) => {
+    impl<$($gen)*> $crate::CallOnce<($($arg_tp,)*)> for $type
+      where $($bound)* {
+      type Output = $ret;
+
+      #[inline(always,)]
+      fn call_once(self, args : ($($arg_tp,)*),) -> Self::Output { $crate::Call::call(&self, args,) }
+    }
+
+    impl<$($gen)*> $crate::CallMut<($($arg_tp,)*)> for $type
+      where $($bound)* {
+      #[inline(always,)]
+      fn call_mut(&mut self, args : ($($arg_tp,)*),) -> Self::Output { $crate::Call::call(self, args,) }
+    }
+
+    impl<$($gen)*> $crate::Call<($($arg_tp,)*)> for $type
+      where $($bound)* {
+      fn call(&$self_, ($($arg,)*) : ($($arg_tp,)*),) -> Self::Output $body
+    }
+  };
+  (FnMut [$self_:ident] [$($gen:tt)*] [$($arg_tp:ty,)*] [$type:ty] [$($bound:tt)*] [$($arg:pat,)*] -> $ret:ty $body:block) => {
+    impl<$($gen)*> $crate::CallOnce<($($arg_tp,)*)> for $type
+      where $($bound)* {
+      type Output = $ret;
+
+      #[inline(always,)]
+      fn call_once(mut self, args : ($($arg_tp,)*),) -> Self::Output { $crate::CallMut::call_mut(&mut self, args,) }
+    }
+
+    impl<$($gen)*> $crate::CallMut<($($arg_tp,)*)> for $type
+      where $($bound)* {
+      fn call_mut(&mut $self_, ($($arg,)*) : ($($arg_tp,)*),) -> Self::Output $body
+    }
+  };
+  (FnOnce [mut $self_:ident] [$($gen:tt)*] [$($arg_tp:ty,)*] [$type:ty] [$($bound:tt)*] [$($arg:pat,)*] -> $ret:ty $body:block) => {
+    impl<$($gen)*> $crate::CallOnce<($($arg_tp,)*)> for $type
+      where $($bound)* {
+      type Output = $ret;
+
+      fn call_once(mut $self_, ($($arg,)*) : ($($arg_tp,)*),) -> Self::Output $body
+    }
+  };
+  (FnOnce [$self_:ident] [$($gen:tt)*] [$($arg_tp:ty,)*] [$type:ty] [$($bound:tt)*] [$($arg:pat,)*] -> $ret:ty $body:block) => {
+    impl<$($gen)*> $crate::CallOnce<($($arg_tp,)*)> for $type
+      where $($bound)* {
+      type Output = $ret;
+
+      fn call_once($self_, ($($arg,)*) : ($($arg_tp,)*),) -> Self::Output $body
+    }
+  };
+  (@partial $($rest:tt)*) => { ::core::compile_error!("currying with `impl_fn` requires a nightly toolchain",); };
+}
+
+#[cfg(all(test, nightly,),)]
 mod tests {
   struct AssertFn;
 
@@ -59,10 +461,158 @@ mod tests {
 
   impl_fn!(<'a, 'b, A, B,> Fn(&'a bool, &'b mut A, B,) for AssertFn => (a, _, b,) -> B { assert!(*a); b });
 
+  impl_fn!(<'a, A: PartialEq + 'a,> Fn(&'a A, &'a A, A,) for AssertFn => (a, b, _,) -> bool { a == b });
+
+  impl_fn!(<A,> Fn(A, A, A, A,) for AssertFn where A: core::ops::Add<Output = A>, => (a, b, c, d,) -> A { a + b + c + d });
+
+  impl_fn!(<const N: usize,> Fn([i32; N],) for AssertFn => (arr,) -> usize { arr.len() + N });
+
+  impl_fn!(<T: Into<i64>> Fn(T, T,) for AssertFn => (a, b,) -> i64 { a.into() * b.into() });
+
+  struct AssertScale { factor: i32, }
+
+  impl_fn!(call Fn(i32,) for AssertScale => |self, (x,)| -> i32 { x * self.factor });
+
+  impl_fn!(<'a,> Fn(&'a mut i32,) for AssertScale => |self, (x,)| -> () { *x *= self.factor });
+
+  #[derive(Clone, Copy,)]
+  struct AssertOverloads;
+
+  impl_fn!(for AssertOverloads {
+    Fn() => () -> u8 { 0 },
+    Fn(char,) => (x,) -> (char, char) { (x, x) },
+  });
+
+  impl_fn!(curry for AssertOverloads {
+    Fn(u8, u16,) => (a, b,) -> u32 { a as u32 + b as u32 },
+    Fn(i8, i8, i8, i8, i8, i8,) => (a, b, c, d, e, f,) -> i8 { a + b + c + d + e + f },
+  });
+
+  struct AssertFnMut(u32);
+
+  impl_fn!(FnMut() for AssertFnMut => |self, ()| -> u32 { self.0 += 1; self.0 });
+
+  impl_fn!(<A,> FnMut(A,) for AssertFnMut => (a,) -> A { a });
+
+  struct AssertFnOnce<T>(T);
+
+  impl_fn!(<T,> FnOnce() for AssertFnOnce<T> => |self, ()| -> T { self.0 });
+
+  impl_fn!(<T: Clone,> FnOnce(T,) for AssertFnOnce<T> => |mut self, (x,)| -> (T, T) { let y = core::mem::replace(&mut self.0, x); (y, self.0) });
+
+  curried_fn! {
+    fn assert_curried(a: u8, b: u16, c: u32,) -> u32 { a as u32 + b as u32 + c }
+  }
+
+  #[test]
+  fn test_curried_fn() {
+    const F: assert_curried = assert_curried;
+
+    assert_eq!(F(1, 2, 3,), 6);
+    assert_eq!(F(1, 2,)(3), 6);
+    assert_eq!(F(1)(2)(3), 6);
+  }
+
   #[test]
   fn test_impls() {
     assert_eq!(AssertFn(), ());
-    assert_eq!(AssertFn(false, 42,), true);
+    assert!(AssertFn(false, 42,));
     assert_eq!(AssertFn(&true, &mut 0, 'c'), 'c');
+    assert!(AssertFn(&1, &1, 2,));
+    assert_eq!(AssertFn(1, 2, 3, 4,), 10);
+    assert_eq!(AssertFn([1, 2, 3,],), 6);
+    assert_eq!(AssertFn(6i32, 7i32,), 42);
+  }
+
+  #[test]
+  fn test_impls_self() {
+    let scale = AssertScale { factor: 3, };
+    let mut x = 2;
+
+    assert_eq!(scale(2), 6);
+    assert_eq!(scale.call(2), 6);
+    scale(&mut x);
+    assert_eq!(x, 6);
+    assert_eq!(FnOnce::call_once(scale, (1,),), 3);
+  }
+
+  #[test]
+  fn test_impls_overloads() {
+    assert_eq!(AssertOverloads(), 0);
+    assert_eq!(AssertOverloads('c'), ('c', 'c'));
+    assert_eq!(AssertOverloads(1u8, 2u16,), 3);
+    assert_eq!(AssertOverloads(1u8)(2u16), 3);
+    assert_eq!(AssertOverloads(1i8, 1i8, 1i8, 1i8, 1i8, 1i8,), 6);
+    assert_eq!(AssertOverloads(1i8, 1i8, 1i8,)(1i8)(1i8)(1i8), 6);
+  }
+
+  #[test]
+  fn test_impls_mut() {
+    let mut f = AssertFnMut(0,);
+
+    assert_eq!(f(), 1);
+    assert_eq!(f(), 2);
+    assert_eq!(f('c'), 'c');
+    assert_eq!(f(), 3);
+  }
+
+  #[test]
+  fn test_impls_once() {
+    assert_eq!(AssertFnOnce('c')(), 'c');
+    assert_eq!(AssertFnOnce(1)(2), (1, 2));
+  }
+}
+
+#[cfg(all(test, not(nightly,),),)]
+mod tests {
+  use crate::{Call, CallMut, CallOnce,};
+
+  struct AssertFn(i32,);
+
+  impl_fn!(call Fn(i32,) for AssertFn => |self, (x)| -> i32 { self.0 + x });
+
+  impl_fn!(Fn(bool, i32,) for AssertFn => |self, (b, x)| -> i32 { if b { self.0 } else { x } });
+
+  struct AssertFnMut(i32,);
+
+  impl_fn!(call FnMut() for AssertFnMut => |self, ()| -> i32 { self.0 += 1; self.0 });
+
+  struct AssertFnOnce(char,);
+
+  impl_fn!(call FnOnce() for AssertFnOnce => |self, ()| -> char { self.0 });
+
+  impl_fn!(FnOnce(char,) for AssertFnOnce => |mut self, (c)| -> char { self.0 = c; self.0 });
+
+  #[derive(Clone, Copy,)]
+  struct AssertOverloads;
+
+  impl_fn!(for AssertOverloads {
+    Fn() => () -> u8 { 0 },
+    <A,> Fn(A, A,) => (a, _,) -> A { a },
+  });
+
+  #[test]
+  fn test_impls_stable() {
+    let mut f = AssertFnMut(0,);
+
+    assert_eq!(Call::call(&AssertFn(1,), (2,),), 3);
+    assert_eq!(AssertFn(1,).call_once((2,),), 3);
+    assert_eq!(f.call_mut((),), 1);
+    assert_eq!(f.call_once((),), 2);
+    assert_eq!(AssertFnOnce('c',).call_once((),), 'c');
+    assert_eq!(Call::call(&AssertFn(1,), (true, 2,),), 1);
+    assert_eq!(AssertFnOnce('c',).call_once(('d',),), 'd');
+    assert_eq!(Call::call(&AssertOverloads, (),), 0);
+    assert_eq!(Call::call(&AssertOverloads, ('a', 'b',),), 'a');
+  }
+
+  #[test]
+  fn test_impls_inherent() {
+    let mut f = AssertFnMut(0,);
+
+    assert_eq!(AssertFn(1,).call(2,), 3);
+    assert_eq!(f.call(), 1);
+    assert_eq!(f.call(), 2);
+    assert_eq!(AssertFnOnce('c',).call(), 'c');
   }
 }
@@ -0,0 +1,135 @@
+//! Author --- DMorgan  
+//! Last Moddified --- 2026-10-19
+
+/// The function `P` with the next argument from `Y` bound and the remaining arguments `Z`.
+type Inner<P, Y, Z,> = (<P as FnOnce<(<Y as Iterator>::Item,)>>::Output, Z,);
+
+/// An iterator over the results of a curried binary function applied to the Cartesian product
+/// of two iterators.
+/// 
+/// Each item of the first iterator is bound once and the partially applied function is reused
+/// for every item of the second iterator, which is cloned to be restarted.
+/// 
+/// Usually constructed with the `grid` method of `Curry2`.
+/// 
+/// ```rust
+/// use curry::*;
+/// 
+/// let grid = Curry2(|a: i32, b: i32| a * b).grid(1..=2, 1..=3,).collect::<Vec<_>>();
+/// assert_eq!(grid, [1, 2, 3, 2, 4, 6,]);
+/// ```
+pub struct Grid2<G, X, Y,>
+  where X: Iterator,
+    G: FnMut<(X::Item,)>, {
+  /// The curried function being applied.
+  func: G,
+  /// The remaining first arguments.
+  xs: X,
+  /// All of the second arguments.
+  ys: Y,
+  /// The function with the current first argument bound and the remaining second arguments.
+  outer: Option<(G::Output, Y,)>,
+}
+
+impl<G, X, Y,> Grid2<G, X, Y,>
+  where X: Iterator,
+    G: FnMut<(X::Item,)>, {
+  /// Constructs a new `Grid2` applying `func` to the product of `xs` and `ys`.
+  #[inline]
+  pub fn new(func: G, xs: X, ys: Y,) -> Self { Grid2 { func, xs, ys, outer: None, } }
+}
+
+impl<G, X, Y,> Iterator for Grid2<G, X, Y,>
+  where X: Iterator,
+    Y: Iterator + Clone,
+    G: FnMut<(X::Item,)>,
+    G::Output: FnMut<(Y::Item,)>, {
+  type Item = <G::Output as FnOnce<(Y::Item,)>>::Output;
+
+  fn next(&mut self,) -> Option<Self::Item> {
+    loop {
+      if let Some((p, ys,)) = &mut self.outer {
+        if let Some(y) = ys.next() { return Some(p(y,)) }
+
+        self.outer = None;
+      }
+
+      let x = self.xs.next()?;
+      self.outer = Some(((self.func)(x,), self.ys.clone(),),);
+    }
+  }
+}
+
+/// An iterator over the results of a curried ternary function applied to the Cartesian product
+/// of three iterators.
+/// 
+/// Each prefix of the arguments is bound once and the partially applied function is reused for
+/// every remaining combination, the later iterators are cloned to be restarted.
+/// 
+/// Usually constructed with the `grid` method of `Curry3`.
+/// 
+/// ```rust
+/// use curry::*;
+/// 
+/// let grid = Curry3(|a: i32, b: i32, c: i32| a * 100 + b * 10 + c).grid(1..=2, 1..=2, 1..=2,);
+/// assert_eq!(grid.collect::<Vec<_>>(), [111, 112, 121, 122, 211, 212, 221, 222,]);
+/// ```
+pub struct Grid3<G, X, Y, Z,>
+  where X: Iterator,
+    Y: Iterator,
+    G: FnMut<(X::Item,)>,
+    G::Output: FnOnce<(Y::Item,)>, {
+  /// The curried function being applied.
+  func: G,
+  /// The remaining first arguments.
+  xs: X,
+  /// All of the second arguments.
+  ys: Y,
+  /// All of the third arguments.
+  zs: Z,
+  /// The function with the current first argument bound and the remaining second arguments.
+  outer: Option<(G::Output, Y,)>,
+  /// The function with the current first two arguments bound and the remaining third arguments.
+  inner: Option<Inner<G::Output, Y, Z,>>,
+}
+
+impl<G, X, Y, Z,> Grid3<G, X, Y, Z,>
+  where X: Iterator,
+    Y: Iterator,
+    G: FnMut<(X::Item,)>,
+    G::Output: FnOnce<(Y::Item,)>, {
+  /// Constructs a new `Grid3` applying `func` to the product of `xs`, `ys` and `zs`.
+  #[inline]
+  pub fn new(func: G, xs: X, ys: Y, zs: Z,) -> Self { Grid3 { func, xs, ys, zs, outer: None, inner: None, } }
+}
+
+impl<G, X, Y, Z,> Iterator for Grid3<G, X, Y, Z,>
+  where X: Iterator,
+    Y: Iterator + Clone,
+    Z: Iterator + Clone,
+    G: FnMut<(X::Item,)>,
+    G::Output: FnMut<(Y::Item,)>,
+    <G::Output as FnOnce<(Y::Item,)>>::Output: FnMut<(Z::Item,)>, {
+  type Item = <<G::Output as FnOnce<(Y::Item,)>>::Output as FnOnce<(Z::Item,)>>::Output;
+
+  fn next(&mut self,) -> Option<Self::Item> {
+    loop {
+      if let Some((p, zs,)) = &mut self.inner {
+        if let Some(z) = zs.next() { return Some(p(z,)) }
+
+        self.inner = None;
+      }
+      if let Some((p, ys,)) = &mut self.outer {
+        if let Some(y) = ys.next() {
+          self.inner = Some((p(y,), self.zs.clone(),),);
+          continue;
+        }
+
+        self.outer = None;
+      }
+
+      let x = self.xs.next()?;
+      self.outer = Some(((self.func)(x,), self.ys.clone(),),);
+    }
+  }
+}
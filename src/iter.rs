@@ -0,0 +1,154 @@
+//! Author --- DMorgan  
+//! Last Moddified --- 2026-10-19
+
+use core::iter::Zip;
+
+/// Iterator adaptors which apply curried functions one argument at a time.
+/// 
+/// Every function is called as `f(a)(b)` so `CurryN`, partially applied `CurryN` and nested
+/// closures can all be passed directly.
+/// 
+/// ```rust
+/// use curry::*;
+/// 
+/// let add = Curry2(|a: i32, b: i32| a + b);
+/// let sums = [1, 2, 3,].iter().copied().zip_with([10, 20, 30,], add).collect::<Vec<_>>();
+/// assert_eq!(sums, [11, 22, 33,]);
+/// 
+/// let weighted = Curry3(|w: i32, acc: i32, x: i32| acc + w * x);
+/// assert_eq!([1, 2, 3,].iter().copied().fold_curried(0, weighted(2)), 12);
+/// ```
+pub trait CurriedIterator: Iterator + Sized {
+  /// Zips `self` with `other` applying `func` to each pair of items.
+  #[inline]
+  fn zip_with<J, F,>(self, other: J, func: F,) -> ZipWith<Self, J::IntoIter, F,>
+    where J: IntoIterator,
+      F: FnMut<(Self::Item,)>,
+      F::Output: FnOnce<(J::Item,)>, {
+    ZipWith { iter: self.zip(other,), func, }
+  }
+  /// Zips `self` with `b` and `c` applying `func` to each triple of items.
+  #[inline]
+  fn zip_with3<J, K, F,>(self, b: J, c: K, func: F,) -> ZipWith3<Self, J::IntoIter, K::IntoIter, F,>
+    where J: IntoIterator,
+      K: IntoIterator,
+      F: FnMut<(Self::Item,)>,
+      F::Output: FnOnce<(J::Item,)>,
+      <F::Output as FnOnce<(J::Item,)>>::Output: FnOnce<(K::Item,)>, {
+    ZipWith3 { iter: self.zip(b,).zip(c,), func, }
+  }
+  /// Threads a state through `func` yielding each new state.
+  /// 
+  /// ```rust
+  /// use curry::*;
+  /// 
+  /// let totals = [1, 2, 3,].iter().copied().scan_curried(0, Curry2(|acc: i32, x: i32| acc + x)).collect::<Vec<_>>();
+  /// assert_eq!(totals, [1, 3, 6,]);
+  /// ```
+  #[inline]
+  fn scan_curried<S, F,>(self, init: S, func: F,) -> ScanCurried<Self, S, F,>
+    where S: Clone,
+      F: FnMut<(S,)>,
+      F::Output: FnOnce<(Self::Item,), Output = S>, {
+    ScanCurried { iter: self, state: Some(init,), func, }
+  }
+  /// Folds every item into an accumulator with `func`.
+  #[inline]
+  fn fold_curried<B, F,>(self, init: B, mut func: F,) -> B
+    where F: FnMut<(B,)>,
+      F::Output: FnOnce<(Self::Item,), Output = B>, {
+    self.fold(init, move |acc, x| func(acc,)(x,),)
+  }
+}
+
+impl<I,> CurriedIterator for I
+  where I: Iterator, {}
+
+/// An iterator applying a curried function to the items of two iterators.
+/// 
+/// Constructed by [`CurriedIterator::zip_with`].
+#[derive(Clone, Debug,)]
+pub struct ZipWith<I, J, F,> {
+  /// The zipped iterators.
+  iter: Zip<I, J,>,
+  /// The function being applied.
+  func: F,
+}
+
+impl<I, J, F,> Iterator for ZipWith<I, J, F,>
+  where I: Iterator,
+    J: Iterator,
+    F: FnMut<(I::Item,)>,
+    F::Output: FnOnce<(J::Item,)>, {
+  type Item = <F::Output as FnOnce<(J::Item,)>>::Output;
+
+  #[inline]
+  fn next(&mut self,) -> Option<Self::Item> {
+    let (a, b,) = self.iter.next()?;
+
+    Some((self.func)(a,)(b,))
+  }
+  #[inline]
+  fn size_hint(&self,) -> (usize, Option<usize>,) { self.iter.size_hint() }
+}
+
+/// An iterator applying a curried function to the items of three iterators.
+/// 
+/// Constructed by [`CurriedIterator::zip_with3`].
+#[derive(Clone, Debug,)]
+pub struct ZipWith3<I, J, K, F,> {
+  /// The zipped iterators.
+  iter: Zip<Zip<I, J,>, K,>,
+  /// The function being applied.
+  func: F,
+}
+
+impl<I, J, K, F,> Iterator for ZipWith3<I, J, K, F,>
+  where I: Iterator,
+    J: Iterator,
+    K: Iterator,
+    F: FnMut<(I::Item,)>,
+    F::Output: FnOnce<(J::Item,)>,
+    <F::Output as FnOnce<(J::Item,)>>::Output: FnOnce<(K::Item,)>, {
+  type Item = <<F::Output as FnOnce<(J::Item,)>>::Output as FnOnce<(K::Item,)>>::Output;
+
+  #[inline]
+  fn next(&mut self,) -> Option<Self::Item> {
+    let ((a, b,), c,) = self.iter.next()?;
+
+    Some((self.func)(a,)(b,)(c,))
+  }
+  #[inline]
+  fn size_hint(&self,) -> (usize, Option<usize>,) { self.iter.size_hint() }
+}
+
+/// An iterator threading a state through a curried function.
+/// 
+/// Constructed by [`CurriedIterator::scan_curried`].
+#[derive(Clone, Debug,)]
+pub struct ScanCurried<I, S, F,> {
+  /// The iterator being scanned.
+  iter: I,
+  /// The current state.
+  state: Option<S>,
+  /// The function being applied.
+  func: F,
+}
+
+impl<I, S, F,> Iterator for ScanCurried<I, S, F,>
+  where I: Iterator,
+    S: Clone,
+    F: FnMut<(S,)>,
+    F::Output: FnOnce<(I::Item,), Output = S>, {
+  type Item = S;
+
+  fn next(&mut self,) -> Option<Self::Item> {
+    let x = self.iter.next()?;
+    let state = (self.func)(self.state.take()?,)(x,);
+
+    self.state = Some(state.clone(),);
+    Some(state)
+  }
+  #[inline]
+  fn size_hint(&self,) -> (usize, Option<usize>,) { self.iter.size_hint() }
+}
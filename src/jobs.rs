@@ -0,0 +1,143 @@
+//! A small thread pool for running saturated calls of curried functions.
+//! 
+//! Author --- DMorgan  
+//! Last Moddified --- 2026-10-19
+
+use crate::Thunk;
+use core::fmt;
+use std::{
+  boxed::Box,
+  panic::{self, AssertUnwindSafe,},
+  sync::{mpsc::{self, Receiver, Sender,}, Arc, Mutex, PoisonError,},
+  thread::{self, JoinHandle,},
+  vec::Vec,
+};
+
+/// A job queued on a [`ThreadPool`].
+type Job = Box<dyn FnOnce() + Send + 'static>;
+
+/// A fixed number of worker threads running queued jobs.
+/// 
+/// Dropping the pool waits for every queued job to finish.
+/// 
+/// ```rust
+/// use curry::{*, jobs::*,};
+/// 
+/// let pool = ThreadPool::new(2,);
+/// let handle = spawn_curried(&pool, Curry3(|a: i32, b: i32, c: i32| a + b + c)(1, 2), 3,);
+/// assert_eq!(handle.join(), Ok(6));
+/// ```
+/// 
+/// Partials which can not be sent to another thread are rejected at compile time.
+/// 
+/// ```rust,compile_fail
+/// use curry::{*, jobs::*,};
+/// use std::rc::Rc;
+/// 
+/// let pool = ThreadPool::new(1,);
+/// spawn_curried(&pool, Curry2(|a: Rc<i32>, b: i32| *a + b)(Rc::new(1,)), 2,);
+/// ```
+pub struct ThreadPool {
+  /// Queues jobs for the workers.
+  queue: Option<Sender<Job,>>,
+  /// The worker threads.
+  workers: Vec<JoinHandle<()>>,
+}
+
+impl ThreadPool {
+  /// Constructs a new `ThreadPool` with `threads` workers.
+  /// 
+  /// # Panics
+  /// 
+  /// If `threads` is zero or a thread could not be spawned.
+  pub fn new(threads: usize,) -> Self {
+    assert!(threads > 0, "`ThreadPool` requires at least one thread",);
+
+    let (queue, jobs,) = mpsc::channel::<Job,>();
+    let jobs = Arc::new(Mutex::new(jobs,),);
+    let workers = (0..threads).map(|_| {
+      let jobs = jobs.clone();
+
+      thread::spawn(move || Self::work(&jobs,),)
+    },).collect();
+
+    ThreadPool { queue: Some(queue,), workers, }
+  }
+  /// Queues `thunk` to be run by a worker.
+  pub fn spawn<T,>(&self, thunk: T,) -> JobHandle<T::Output,>
+    where T: FnOnce<()> + Send + 'static,
+      T::Output: Send + 'static, {
+    let (send, result,) = mpsc::channel();
+    let job: Job = Box::new(move || { let _ = send.send(thunk(),); },);
+
+    if let Some(queue) = &self.queue { let _ = queue.send(job,); }
+
+    JobHandle { result, }
+  }
+  /// Runs jobs until the queue is closed.
+  fn work(jobs: &Mutex<Receiver<Job,>>,) {
+    loop {
+      let job = jobs.lock().unwrap_or_else(PoisonError::into_inner,).recv();
+
+      match job {
+        // A panicking job drops its result sender which is reported by its `JobHandle`.
+        Ok(job,) => { let _ = panic::catch_unwind(AssertUnwindSafe(job,),); },
+        Err(_) => return,
+      }
+    }
+  }
+}
+
+impl Drop for ThreadPool {
+  fn drop(&mut self,) {
+    self.queue = None;
+    for worker in self.workers.drain(..) { let _ = worker.join(); }
+  }
+}
+
+impl fmt::Debug for ThreadPool {
+  fn fmt(&self, fmt: &mut fmt::Formatter,) -> fmt::Result {
+    fmt.debug_struct("ThreadPool",).field("threads", &self.workers.len(),).finish()
+  }
+}
+
+/// Waits for the result of a job queued on a [`ThreadPool`].
+#[derive(Debug,)]
+pub struct JobHandle<T,> {
+  /// Receives the result of the job.
+  result: Receiver<T,>,
+}
+
+impl<T,> JobHandle<T,> {
+  /// Blocks until the job has finished and returns its result.
+  #[inline]
+  pub fn join(self,) -> Result<T, JobError,> { self.result.recv().map_err(|_| JobError,) }
+  /// Returns the result of the job if it has finished.
+  #[inline]
+  pub fn try_join(&self,) -> Option<Result<T, JobError,>> {
+    match self.result.try_recv() {
+      Ok(result,) => Some(Ok(result,),),
+      Err(mpsc::TryRecvError::Empty) => None,
+      Err(mpsc::TryRecvError::Disconnected) => Some(Err(JobError,),),
+    }
+  }
+}
+
+/// The error returned when a job panicked before producing its result.
+#[derive(PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Copy, Default, Debug,)]
+pub struct JobError;
+
+impl fmt::Display for JobError {
+  fn fmt(&self, fmt: &mut fmt::Formatter,) -> fmt::Result { fmt.write_str("job panicked",) }
+}
+
+impl std::error::Error for JobError {}
+
+/// Queues the saturated call of `partial` with its final argument `arg` on `pool`.
+#[inline]
+pub fn spawn_curried<P, A,>(pool: &ThreadPool, partial: P, arg: A,) -> JobHandle<P::Output,>
+  where P: FnOnce<(A,)> + Send + 'static,
+    A: Send + 'static,
+    P::Output: Send + 'static, {
+  pool.spawn(Thunk::new((arg,), partial,),)
+}
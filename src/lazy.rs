@@ -0,0 +1,167 @@
+//! Author --- DMorgan  
+//! Last Moddified --- 2026-10-19
+
+use crate::*;
+use core::{fmt, ops::Deref, cell::{Cell, OnceCell,},};
+
+/// A value which is computed from a thunk the first time it is accessed.
+/// 
+/// ```rust
+/// use curry::*;
+/// use core::cell::Cell;
+/// 
+/// let calls = Cell::new(0,);
+/// let f = Lazy::new(Curry3(|a: i32, b: i32, c: i32| { calls.set(calls.get() + 1,); a + b + c }).defer(1, 2, 3));
+/// assert_eq!(calls.get(), 0);
+/// assert_eq!(*f, 6);
+/// assert_eq!(*f.get(), 6);
+/// assert_eq!(calls.get(), 1);
+/// ```
+pub struct Lazy<T,>
+  where T: FnOnce<()>, {
+  /// The cached value.
+  value: OnceCell<T::Output>,
+  /// The thunk which has not yet been forced.
+  thunk: Cell<Option<T>>,
+}
+
+impl<T,> Lazy<T,>
+  where T: FnOnce<()>, {
+  /// Constructs a new `Lazy` from `thunk`.
+  #[inline]
+  pub const fn new(thunk: T,) -> Self { Lazy { value: OnceCell::new(), thunk: Cell::new(Some(thunk,),), } }
+  /// Forces the thunk if it has not been forced yet and references the value.
+  /// 
+  /// # Panics
+  /// 
+  /// If a previous call to the thunk panicked.
+  pub fn get(&self,) -> &T::Output {
+    self.value.get_or_init(|| match self.thunk.take() {
+      Some(thunk,) => thunk(),
+      None => panic!("`Lazy` instance has previously been poisoned",),
+    },)
+  }
+  /// References the value if it has been computed.
+  #[inline]
+  pub fn try_get(&self,) -> Option<&T::Output> { self.value.get() }
+  /// Forces the thunk if it has not been forced yet and returns the value.
+  #[inline]
+  pub fn into_inner(self,) -> T::Output {
+    self.get();
+    self.value.into_inner().expect("`Lazy` value was not initialised",)
+  }
+}
+
+impl<F, Args,> Thunk<F, Args,>
+  where Self: FnOnce<()>, {
+  /// Caches the result of forcing this thunk.
+  #[inline]
+  pub const fn lazy(self,) -> Lazy<Self,> { Lazy::new(self,) }
+}
+
+impl<T,> Deref for Lazy<T,>
+  where T: FnOnce<()>, {
+  type Target = T::Output;
+
+  #[inline]
+  fn deref(&self,) -> &Self::Target { self.get() }
+}
+
+impl<T,> From<T> for Lazy<T,>
+  where T: FnOnce<()>, {
+  #[inline]
+  fn from(thunk: T,) -> Self { Lazy::new(thunk,) }
+}
+
+impl<T,> fmt::Debug for Lazy<T,>
+  where T: FnOnce<()>,
+    T::Output: fmt::Debug, {
+  fn fmt(&self, fmt: &mut fmt::Formatter,) -> fmt::Result {
+    fmt.debug_struct("Lazy",)
+      .field("value", &self.value.get(),)
+      .finish()
+  }
+}
+
+#[cfg(feature = "std",)]
+pub use self::sync::*;
+
+#[cfg(feature = "std",)]
+mod sync {
+  use std::sync::{Mutex, OnceLock, PoisonError,};
+  use core::{fmt, ops::Deref,};
+
+  /// A thread safe value which is computed from a thunk the first time it is accessed.
+  /// 
+  /// ```rust
+  /// use curry::*;
+  /// use std::sync::Arc;
+  /// 
+  /// let f = Arc::new(SyncLazy::new(Curry2(|a: i32, b: i32| a * b).defer(6, 7)));
+  /// let g = f.clone();
+  /// assert_eq!(std::thread::spawn(move || *g.get()).join().unwrap(), 42);
+  /// assert_eq!(**f, 42);
+  /// ```
+  pub struct SyncLazy<T,>
+    where T: FnOnce<()>, {
+    /// The cached value.
+    value: OnceLock<T::Output>,
+    /// The thunk which has not yet been forced.
+    thunk: Mutex<Option<T>>,
+  }
+
+  impl<T,> SyncLazy<T,>
+    where T: FnOnce<()>, {
+    /// Constructs a new `SyncLazy` from `thunk`.
+    #[inline]
+    pub const fn new(thunk: T,) -> Self { SyncLazy { value: OnceLock::new(), thunk: Mutex::new(Some(thunk,),), } }
+    /// Forces the thunk if it has not been forced yet and references the value.
+    /// 
+    /// # Panics
+    /// 
+    /// If a previous call to the thunk panicked.
+    pub fn get(&self,) -> &T::Output {
+      self.value.get_or_init(|| {
+        let thunk = self.thunk.lock().unwrap_or_else(PoisonError::into_inner,).take();
+
+        match thunk {
+          Some(thunk,) => thunk(),
+          None => panic!("`SyncLazy` instance has previously been poisoned",),
+        }
+      },)
+    }
+    /// References the value if it has been computed.
+    #[inline]
+    pub fn try_get(&self,) -> Option<&T::Output> { self.value.get() }
+    /// Forces the thunk if it has not been forced yet and returns the value.
+    #[inline]
+    pub fn into_inner(self,) -> T::Output {
+      self.get();
+      self.value.into_inner().expect("`SyncLazy` value was not initialised",)
+    }
+  }
+
+  impl<T,> Deref for SyncLazy<T,>
+    where T: FnOnce<()>, {
+    type Target = T::Output;
+
+    #[inline]
+    fn deref(&self,) -> &Self::Target { self.get() }
+  }
+
+  impl<T,> From<T> for SyncLazy<T,>
+    where T: FnOnce<()>, {
+    #[inline]
+    fn from(thunk: T,) -> Self { SyncLazy::new(thunk,) }
+  }
+
+  impl<T,> fmt::Debug for SyncLazy<T,>
+    where T: FnOnce<()>,
+      T::Output: fmt::Debug, {
+    fn fmt(&self, fmt: &mut fmt::Formatter,) -> fmt::Result {
+      fmt.debug_struct("SyncLazy",)
+        .field("value", &self.value.get(),)
+        .finish()
+    }
+  }
+}
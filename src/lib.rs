@@ -1,18 +1,22 @@
 //! Provides types for currying and uncurrying functions with up to 6 parameters.
 //! 
+//! The types in this crate implement the `Fn*` traits and so require a nightly toolchain, on a
+//! stable toolchain only [`impl_fn`] and the [`Call`] traits are available.
+//! 
 //! Author --- DMorgan  
-//! Last Moddified --- 2021-03-03
+//! Last Moddified --- 2026-10-19
 
 #![no_std]
 #![deny(missing_docs,)]
-#![feature(
-  coerce_unsized, fn_traits, unboxed_closures, const_mut_refs, const_fn, external_doc,
-)]
-#![doc(test(attr(feature(fn_traits, unboxed_closures,),),),)]
+#![cfg_attr(nightly, feature(coerce_unsized, fn_traits, unboxed_closures,),)]
+#![cfg_attr(nightly, doc(test(attr(feature(fn_traits, unboxed_closures,),),),),)]
 
-#[cfg(test,)]
+#[cfg(any(test, feature = "std",),)]
 extern crate std;
+#[cfg(feature = "alloc",)]
+extern crate alloc;
 
+#[cfg(nightly,)]
 mod curry {
   pub mod curry2;
   pub mod curry3;
@@ -20,15 +24,63 @@ mod curry {
   pub mod curry5;
   pub mod curry6;
 }
+#[cfg(nightly,)]
 mod uncurry;
+#[cfg(nightly,)]
+mod map_arg;
+#[cfg(nightly,)]
+mod rclosure;
+#[cfg(nightly,)]
+mod defaults;
+#[cfg(nightly,)]
+mod thunk;
+#[cfg(nightly,)]
+mod lazy;
+#[cfg(nightly,)]
+mod combinators;
+#[cfg(nightly,)]
+mod iter;
+#[cfg(nightly,)]
+mod grid;
+#[cfg(nightly,)]
+mod apply;
+#[cfg(nightly,)]
+mod unsafe_fn;
+#[cfg(nightly,)]
+mod args;
+#[cfg(nightly,)]
+mod pretty;
+#[cfg(nightly,)]
+mod traced;
+#[cfg(all(nightly, feature = "alloc",),)]
+pub mod ffi;
+#[cfg(all(nightly, feature = "std",),)]
+pub mod jobs;
+mod call;
 mod fns_macros;
 
+#[cfg(nightly,)]
 pub use self::{
   curry::{curry2::*, curry3::*, curry4::*, curry5::*, curry6::*,},
   uncurry::*,
-  fns_macros::*,
+  map_arg::*,
+  rclosure::*,
+  defaults::*,
+  thunk::*,
+  lazy::*,
+  combinators::*,
+  iter::*,
+  grid::*,
+  apply::*,
+  unsafe_fn::*,
+  args::*,
+  pretty::*,
+  traced::*,
 };
+pub use self::call::*;
+#[cfg(feature = "derive",)]
+pub use curry_derive::fn_object;
 
-#[cfg(doctest,)]
-#[doc(include = "../README.md",)]
+#[cfg(all(doctest, nightly,),)]
+#[doc = include_str!("../README.md",)]
 struct ReadmeDocs;
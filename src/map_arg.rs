@@ -0,0 +1,128 @@
+//! Author --- DMorgan  
+//! Last Moddified --- 2026-10-19
+
+use crate::*;
+use core::ops::CoerceUnsized;
+
+/// A function whose argument at position `I` is pre-processed by `map`.
+/// 
+/// Usually constructed with [`MapArgs::map_arg`].
+/// 
+/// ```rust
+/// use curry::*;
+/// 
+/// let f = Curry3(|a: i32, b: i32, c: i32| a + b + c).map_arg::<1>(|s: &str| s.parse::<i32>().unwrap());
+/// assert_eq!(f(1, "2", 3), 6);
+/// assert_eq!(f(1, "2")(3), 6);
+/// assert_eq!(f(1)("2")(3), 6);
+/// ```
+#[derive(PartialEq, Eq, Hash, Clone, Copy, Default, Debug,)]
+pub struct MapArg<F, G, const I: usize,>
+  where F: ?Sized, {
+  /// The function applied to the argument at position `I`.
+  pub map: G,
+  /// The function being wrapped.
+  pub func: F,
+}
+
+impl<F, G, const I: usize,> MapArg<F, G, I,> {
+  /// Constructs a new `MapArg` from `func` and `map`.
+  #[inline]
+  pub const fn new(func: F, map: G,) -> Self { MapArg { map, func, } }
+}
+
+/// A curried function whose arguments can be pre-processed by position.
+/// 
+/// The position is a parameter of the method while the type of `map` is a parameter of the trait
+/// so that only the position needs to be named, as in `Curry3(f).map_arg::<1>(parse)`.
+pub trait MapArgs<G,>: Sized {
+  /// The curried function with its argument at position `I` pre-processed by `G`.
+  type Output<const I: usize,>;
+
+  /// Pre-processes the argument at position `I` with `map` before it is passed to the function.
+  fn map_arg<const I: usize,>(self, map: G,) -> Self::Output<I,>;
+}
+
+/// Implements `MapArgs` for a curried function type.
+macro_rules! impl_map_args {
+  ($($curry:ident,)+) => {
+    $(impl<F, G,> MapArgs<G,> for $curry<F,> {
+      type Output<const I: usize,> = $curry<MapArg<F, G, I,>,>;
+
+      #[inline]
+      fn map_arg<const I: usize,>(self, map: G,) -> Self::Output<I,> { $curry(MapArg::new(self.0, map,),) }
+    })+
+  };
+}
+
+impl_map_args!(Curry2, Curry3, Curry4, Curry5, Curry6,);
+
+/// Implements the `Fn*` traits for `MapArg` at position `$i` for a single arity.
+macro_rules! impl_map_arg {
+  ($i:literal => ($($pre_tp:ident $pre:ident,)*) $arg_tp:ident $arg:ident, ($($post_tp:ident $post:ident,)*)) => {
+    impl<$($pre_tp,)* $arg_tp, $($post_tp,)* X, Y,> FnOnce<($($pre_tp,)* $arg_tp, $($post_tp,)*)> for MapArg<X, Y, $i,>
+      where Y: FnOnce<($arg_tp,)>,
+        X: FnOnce<($($pre_tp,)* Y::Output, $($post_tp,)*)>, {
+      type Output = X::Output;
+
+      #[inline]
+      extern "rust-call" fn call_once(self, ($($pre,)* $arg, $($post,)*): ($($pre_tp,)* $arg_tp, $($post_tp,)*),) -> Self::Output {
+        (self.func)($($pre,)* (self.map)($arg,), $($post,)*)
+      }
+    }
+
+    impl<$($pre_tp,)* $arg_tp, $($post_tp,)* X, Y,> FnMut<($($pre_tp,)* $arg_tp, $($post_tp,)*)> for MapArg<X, Y, $i,>
+      where Y: FnMut<($arg_tp,)>,
+        X: FnMut<($($pre_tp,)* Y::Output, $($post_tp,)*)>, {
+      #[inline]
+      extern "rust-call" fn call_mut(&mut self, ($($pre,)* $arg, $($post,)*): ($($pre_tp,)* $arg_tp, $($post_tp,)*),) -> Self::Output {
+        (self.func)($($pre,)* (self.map)($arg,), $($post,)*)
+      }
+    }
+
+    impl<$($pre_tp,)* $arg_tp, $($post_tp,)* X, Y,> Fn<($($pre_tp,)* $arg_tp, $($post_tp,)*)> for MapArg<X, Y, $i,>
+      where Y: Fn<($arg_tp,)>,
+        X: Fn<($($pre_tp,)* Y::Output, $($post_tp,)*)>, {
+      #[inline]
+      extern "rust-call" fn call(&self, ($($pre,)* $arg, $($post,)*): ($($pre_tp,)* $arg_tp, $($post_tp,)*),) -> Self::Output {
+        (self.func)($($pre,)* (self.map)($arg,), $($post,)*)
+      }
+    }
+  };
+}
+
+impl_map_arg!(0 => () A a, ());
+
+impl_map_arg!(0 => () A a, (B b,));
+impl_map_arg!(1 => (A a,) B b, ());
+
+impl_map_arg!(0 => () A a, (B b, C c,));
+impl_map_arg!(1 => (A a,) B b, (C c,));
+impl_map_arg!(2 => (A a, B b,) C c, ());
+
+impl_map_arg!(0 => () A a, (B b, C c, D d,));
+impl_map_arg!(1 => (A a,) B b, (C c, D d,));
+impl_map_arg!(2 => (A a, B b,) C c, (D d,));
+impl_map_arg!(3 => (A a, B b, C c,) D d, ());
+
+impl_map_arg!(0 => () A a, (B b, C c, D d, E e,));
+impl_map_arg!(1 => (A a,) B b, (C c, D d, E e,));
+impl_map_arg!(2 => (A a, B b,) C c, (D d, E e,));
+impl_map_arg!(3 => (A a, B b, C c,) D d, (E e,));
+impl_map_arg!(4 => (A a, B b, C c, D d,) E e, ());
+
+impl_map_arg!(0 => () A a, (B b, C c, D d, E e, F f,));
+impl_map_arg!(1 => (A a,) B b, (C c, D d, E e, F f,));
+impl_map_arg!(2 => (A a, B b,) C c, (D d, E e, F f,));
+impl_map_arg!(3 => (A a, B b, C c,) D d, (E e, F f,));
+impl_map_arg!(4 => (A a, B b, C c, D d,) E e, (F f,));
+impl_map_arg!(5 => (A a, B b, C c, D d, E e,) F f, ());
+
+impl<G, T, U, const I: usize,> CoerceUnsized<MapArg<U, G, I,>> for MapArg<T, G, I,>
+  where T: CoerceUnsized<U>,
+    U: ?Sized, {}
+
+#[allow(unused,)]
+fn _assert_coerce_unsized(a: MapArg<&i32, (), 0,>,) {
+  let _: MapArg<&dyn Send, (), 0,> = a;
+}
@@ -0,0 +1,120 @@
+//! Author --- DMorgan  
+//! Last Moddified --- 2026-10-19
+
+use crate::*;
+use core::{any, fmt,};
+
+/// A curried value which can be formatted without its function implementing `Debug`.
+/// 
+/// The function is named by its type name, which is best effort, followed by the applied
+/// arguments and a `_` for each remaining argument.
+/// 
+/// ```rust
+/// use curry::*;
+/// 
+/// fn my_fn(a: i32, b: i32, c: i32, d: i32,) -> i32 { a + b + c + d }
+/// 
+/// assert_eq!(Curry4(my_fn).pretty().to_string(), "Curry4<my_fn>(_, _, _, _)");
+/// assert_eq!(Curry4(my_fn)(1, 2).pretty().to_string(), "Curry4<my_fn>(1, 2, _, _)");
+/// assert_eq!(format!("{:?}", Curry4(my_fn)(1, 2, 3).pretty()), "Curry4<my_fn>(1, 2, 3, _)");
+/// 
+/// // The type names of closures are unstable so only the structure is checked.
+/// let f = Curry2(|a: i32, _: &str| a)(1).pretty().to_string();
+/// assert!(f.starts_with("Curry2<") && f.ends_with(">(1, _)"));
+/// ```
+pub trait DebugCurried {
+  /// Formats the function name, the applied arguments and the remaining arguments.
+  fn fmt_curried(&self, fmt: &mut fmt::Formatter,) -> fmt::Result;
+  /// Wraps the value so that `Debug` and `Display` use [`fmt_curried`](DebugCurried::fmt_curried).
+  #[inline]
+  fn pretty(&self,) -> Pretty<'_, Self,> { Pretty(self,) }
+}
+
+/// Formats a curried value with [`DebugCurried`].
+/// 
+/// Constructed by [`DebugCurried::pretty`].
+#[derive(Clone, Copy,)]
+pub struct Pretty<'a, T,>(&'a T,)
+  where T: ?Sized,;
+
+impl<T,> fmt::Debug for Pretty<'_, T,>
+  where T: DebugCurried + ?Sized, {
+  #[inline]
+  fn fmt(&self, fmt: &mut fmt::Formatter,) -> fmt::Result { self.0.fmt_curried(fmt,) }
+}
+
+impl<T,> fmt::Display for Pretty<'_, T,>
+  where T: DebugCurried + ?Sized, {
+  #[inline]
+  fn fmt(&self, fmt: &mut fmt::Formatter,) -> fmt::Result { self.0.fmt_curried(fmt,) }
+}
+
+/// Strips the module path from a type name.
+fn short_name(name: &str,) -> &str {
+  let end = name.find('<',).unwrap_or(name.len(),);
+  let start = name[..end].rfind("::",).map_or(0, |start| start + 2,);
+
+  &name[start..]
+}
+
+/// Splits a type name of a `CurryN` into its arity and the type name of its function.
+fn curry_parts(name: &str,) -> Option<(usize, &str,)> {
+  let curries = [
+    (2, any::type_name::<Curry2<(),>>(),),
+    (3, any::type_name::<Curry3<(),>>(),),
+    (4, any::type_name::<Curry4<(),>>(),),
+    (5, any::type_name::<Curry5<(),>>(),),
+    (6, any::type_name::<Curry6<(),>>(),),
+  ];
+
+  curries.iter().find_map(|&(arity, curry,)| {
+    let prefix = curry.strip_suffix("<()>",)?;
+
+    Some((arity, name.strip_prefix(prefix,)?.strip_prefix('<',)?.strip_suffix('>',)?,),)
+  },)
+}
+
+/// Formats a function of type `func` taking `arity` arguments, the first of which are `args`.
+/// 
+/// If `func` is itself a `CurryN` its arity and function are used instead.
+fn fmt_curried(fmt: &mut fmt::Formatter, func: &str, arity: usize, args: &[&dyn fmt::Debug],) -> fmt::Result {
+  let (arity, func,) = curry_parts(func,).unwrap_or((arity, func,),);
+
+  write!(fmt, "Curry{}<{}>(", arity, short_name(func,),)?;
+  for (i, arg,) in args.iter().enumerate() {
+    if i > 0 { fmt.write_str(", ",)?; }
+    arg.fmt(fmt,)?;
+  }
+  for i in args.len()..arity {
+    if i > 0 { fmt.write_str(", ",)?; }
+    fmt.write_str("_",)?;
+  }
+
+  fmt.write_str(")",)
+}
+
+/// Implements `DebugCurried` for a `CurryN` and its `ClosureN`.
+macro_rules! impl_debug_curried {
+  ($curry:ident $closure:ident $arity:literal => $($tp:ident $arg:ident,)+) => {
+    impl<F,> DebugCurried for $curry<F,> {
+      #[inline]
+      fn fmt_curried(&self, fmt: &mut fmt::Formatter,) -> fmt::Result {
+        fmt_curried(fmt, any::type_name::<F,>(), $arity, &[],)
+      }
+    }
+
+    impl<$($tp,)+ F,> DebugCurried for $closure<$($tp,)+ F,>
+      where $($tp: fmt::Debug,)+ {
+      #[inline]
+      fn fmt_curried(&self, fmt: &mut fmt::Formatter,) -> fmt::Result {
+        fmt_curried(fmt, any::type_name::<F,>(), $arity, &[$(&self.$arg,)+],)
+      }
+    }
+  };
+}
+
+impl_debug_curried!(Curry2 Closure2 2 => A a,);
+impl_debug_curried!(Curry3 Closure3 3 => A a, B b,);
+impl_debug_curried!(Curry4 Closure4 4 => A a, B b, C c,);
+impl_debug_curried!(Curry5 Closure5 5 => A a, B b, C c, D d,);
+impl_debug_curried!(Curry6 Closure6 6 => A a, B b, C c, D d, E e,);
@@ -0,0 +1,236 @@
+//! Author --- DMorgan  
+//! Last Moddified --- 2026-10-19
+
+use core::ops::CoerceUnsized;
+
+/// Fixes the trailing arguments of a curried function.
+/// 
+/// ```rust
+/// use curry::*;
+/// 
+/// let f = Curry4(|a, b, c, d| a - b - c - d);
+/// assert_eq!(f.apply_right((3, 4,),)(10, 2), 1);
+/// assert_eq!(f.apply_right((2, 3, 4,),)(10), 1);
+/// ```
+pub trait ApplyRight<Args,> {
+  /// The function of the remaining leading arguments.
+  type Output;
+
+  /// Fixes the trailing `args` leaving a function of the leading arguments.
+  fn apply_right(self, args: Args,) -> Self::Output;
+}
+
+/// A closure storing the last parameter of a function.
+/// 
+/// ```rust
+/// use curry::*;
+/// 
+/// let f: RClosure2<_, _> = Curry2(|a, b| a - b).apply_last(1);
+/// assert_eq!(f(3), 2);
+/// ```
+#[derive(PartialEq, Eq, Hash, Clone, Copy, Default, Debug,)]
+pub struct RClosure2<A, F,>
+  where F: ?Sized, {
+  /// The stored `A` parameter.
+  pub a: A,
+  /// The function being wrapped.
+  pub func: F,
+}
+
+impl<A, F,> RClosure2<A, F,> {
+  /// Constructs a new `RClosure2` from `a`, and `func`.
+  #[inline]
+  pub const fn new(a: A, func: F,) -> Self { RClosure2 { a, func, } }
+}
+
+/// A closure storing the last two parameters of a function.
+/// 
+/// ```rust
+/// use curry::*;
+/// 
+/// let f: RClosure3<_, _, _> = Curry3(|a, b, c| a - b - c).apply_right((2, 3,),);
+/// assert_eq!(f(10), 5);
+/// ```
+#[derive(PartialEq, Eq, Hash, Clone, Copy, Default, Debug,)]
+pub struct RClosure3<A, B, F,>
+  where F: ?Sized, {
+  /// The stored `A` parameter.
+  pub a: A,
+  /// The stored `B` parameter.
+  pub b: B,
+  /// The function being wrapped.
+  pub func: F,
+}
+
+impl<A, B, F,> RClosure3<A, B, F,> {
+  /// Constructs a new `RClosure3` from `a`, `b` and `func`.
+  #[inline]
+  pub const fn new(a: A, b: B, func: F,) -> Self { RClosure3 { a, b, func, } }
+}
+
+/// A closure storing the last three parameters of a function.
+/// 
+/// ```rust
+/// use curry::*;
+/// 
+/// let f: RClosure4<_, _, _, _> = Curry4(|a, b, c, d| a - b - c - d).apply_right((2, 3, 4,),);
+/// assert_eq!(f(10), 1);
+/// ```
+#[derive(PartialEq, Eq, Hash, Clone, Copy, Default, Debug,)]
+pub struct RClosure4<A, B, C, F,>
+  where F: ?Sized, {
+  /// The stored `A` parameter.
+  pub a: A,
+  /// The stored `B` parameter.
+  pub b: B,
+  /// The stored `C` parameter.
+  pub c: C,
+  /// The function being wrapped.
+  pub func: F,
+}
+
+impl<A, B, C, F,> RClosure4<A, B, C, F,> {
+  /// Constructs a new `RClosure4` from `a`, `b`, `c`, and `func`.
+  #[inline]
+  pub const fn new(a: A, b: B, c: C, func: F,) -> Self { RClosure4 { a, b, c, func, } }
+}
+
+/// A closure storing the last four parameters of a function.
+/// 
+/// ```rust
+/// use curry::*;
+/// 
+/// let f: RClosure5<_, _, _, _, _> = Curry5(|a, b, c, d, e| a - b - c - d - e).apply_right((2, 3, 4, 5,),);
+/// assert_eq!(f(20), 6);
+/// ```
+#[derive(PartialEq, Eq, Hash, Clone, Copy, Default, Debug,)]
+pub struct RClosure5<A, B, C, D, F,>
+  where F: ?Sized, {
+  /// The stored `A` parameter.
+  pub a: A,
+  /// The stored `B` parameter.
+  pub b: B,
+  /// The stored `C` parameter.
+  pub c: C,
+  /// The stored `D` parameter.
+  pub d: D,
+  /// The function being wrapped.
+  pub func: F,
+}
+
+impl<A, B, C, D, F,> RClosure5<A, B, C, D, F,> {
+  /// Constructs a new `RClosure5` from `a`, `b`, `c`, `d`, and `func`.
+  #[inline]
+  pub const fn new(a: A, b: B, c: C, d: D, func: F,) -> Self { RClosure5 { a, b, c, d, func, } }
+}
+
+/// A closure storing the last five parameters of a function.
+/// 
+/// ```rust
+/// use curry::*;
+/// 
+/// let f: RClosure6<_, _, _, _, _, _> = Curry6(|a, b, c, d, e, f| a - b - c - d - e - f).apply_right((2, 3, 4, 5, 6,),);
+/// assert_eq!(f(30), 10);
+/// ```
+#[derive(PartialEq, Eq, Hash, Clone, Copy, Default, Debug,)]
+pub struct RClosure6<A, B, C, D, E, F,>
+  where F: ?Sized, {
+  /// The stored `A` parameter.
+  pub a: A,
+  /// The stored `B` parameter.
+  pub b: B,
+  /// The stored `C` parameter.
+  pub c: C,
+  /// The stored `D` parameter.
+  pub d: D,
+  /// The stored `E` parameter.
+  pub e: E,
+  /// The function being wrapped.
+  pub func: F,
+}
+
+impl<A, B, C, D, E, F,> RClosure6<A, B, C, D, E, F,> {
+  /// Constructs a new `RClosure6` from `a`, `b`, `c`, `d`, `e`, and `func`.
+  #[inline]
+  pub const fn new(a: A, b: B, c: C, d: D, e: E, func: F,) -> Self { RClosure6 { a, b, c, d, e, func, } }
+}
+
+/// Implements the `Fn*` traits for an `RClosure` called with a single count of leading arguments.
+macro_rules! impl_rclosure {
+  ($name:ident<$($st_tp:ident $st:ident,)*> => ($($ld_tp:ident $ld:ident,)*)) => {
+    impl<$($ld_tp,)* $($st_tp,)* F,> FnOnce<($($ld_tp,)*)> for $name<$($st_tp,)* F,>
+      where F: FnOnce<($($ld_tp,)* $($st_tp,)*)>, {
+      type Output = F::Output;
+
+      #[inline]
+      extern "rust-call" fn call_once(self, ($($ld,)*): ($($ld_tp,)*),) -> Self::Output { (self.func)($($ld,)* $(self.$st,)*) }
+    }
+
+    impl<$($ld_tp,)* $($st_tp,)* F,> FnMut<($($ld_tp,)*)> for $name<$($st_tp,)* F,>
+      where $($st_tp: Clone,)*
+        F: FnMut<($($ld_tp,)* $($st_tp,)*)>, {
+      extern "rust-call" fn call_mut(&mut self, ($($ld,)*): ($($ld_tp,)*),) -> Self::Output { (self.func)($($ld,)* $(self.$st.clone(),)*) }
+    }
+
+    impl<$($ld_tp,)* $($st_tp,)* F,> Fn<($($ld_tp,)*)> for $name<$($st_tp,)* F,>
+      where $($st_tp: Clone,)*
+        F: Fn<($($ld_tp,)* $($st_tp,)*)>, {
+      extern "rust-call" fn call(&self, ($($ld,)*): ($($ld_tp,)*),) -> Self::Output { (self.func)($($ld,)* $(self.$st.clone(),)*) }
+    }
+  };
+}
+
+impl_rclosure!(RClosure2<A a,> => (T t,));
+impl_rclosure!(RClosure2<A a,> => (T t, U u,));
+impl_rclosure!(RClosure2<A a,> => (T t, U u, V v,));
+impl_rclosure!(RClosure2<A a,> => (T t, U u, V v, W w,));
+impl_rclosure!(RClosure2<A a,> => (T t, U u, V v, W w, X x,));
+
+impl_rclosure!(RClosure3<A a, B b,> => (T t,));
+impl_rclosure!(RClosure3<A a, B b,> => (T t, U u,));
+impl_rclosure!(RClosure3<A a, B b,> => (T t, U u, V v,));
+impl_rclosure!(RClosure3<A a, B b,> => (T t, U u, V v, W w,));
+
+impl_rclosure!(RClosure4<A a, B b, C c,> => (T t,));
+impl_rclosure!(RClosure4<A a, B b, C c,> => (T t, U u,));
+impl_rclosure!(RClosure4<A a, B b, C c,> => (T t, U u, V v,));
+
+impl_rclosure!(RClosure5<A a, B b, C c, D d,> => (T t,));
+impl_rclosure!(RClosure5<A a, B b, C c, D d,> => (T t, U u,));
+
+impl_rclosure!(RClosure6<A a, B b, C c, D d, E e,> => (T t,));
+
+impl<A, T, U,> CoerceUnsized<RClosure2<A, U,>> for RClosure2<A, T,>
+  where T: CoerceUnsized<U>,
+    U: ?Sized, {}
+
+impl<A, B, T, U,> CoerceUnsized<RClosure3<A, B, U,>> for RClosure3<A, B, T,>
+  where T: CoerceUnsized<U>,
+    U: ?Sized, {}
+
+impl<A, B, C, T, U,> CoerceUnsized<RClosure4<A, B, C, U,>> for RClosure4<A, B, C, T,>
+  where T: CoerceUnsized<U>,
+    U: ?Sized, {}
+
+impl<A, B, C, D, T, U,> CoerceUnsized<RClosure5<A, B, C, D, U,>> for RClosure5<A, B, C, D, T,>
+  where T: CoerceUnsized<U>,
+    U: ?Sized, {}
+
+impl<A, B, C, D, E, T, U,> CoerceUnsized<RClosure6<A, B, C, D, E, U,>> for RClosure6<A, B, C, D, E, T,>
+  where T: CoerceUnsized<U>,
+    U: ?Sized, {}
+
+#[allow(unused,)]
+fn _assert_coerce_unsized(
+  a: RClosure2<(), &i32,>,
+  b: RClosure3<(), (), &i32,>,
+  c: RClosure4<(), (), (), &i32,>,
+  d: RClosure5<(), (), (), (), &i32,>,
+  e: RClosure6<(), (), (), (), (), &i32,>,
+) {
+  let _: RClosure2<(), &dyn Send,> = a;
+  let _: RClosure3<(), (), &dyn Send,> = b;
+  let _: RClosure4<(), (), (), &dyn Send,> = c;
+  let _: RClosure5<(), (), (), (), &dyn Send,> = d;
+  let _: RClosure6<(), (), (), (), (), &dyn Send,> = e;
+}
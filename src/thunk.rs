@@ -0,0 +1,82 @@
+//! Author --- DMorgan  
+//! Last Moddified --- 2026-10-19
+
+use core::ops::CoerceUnsized;
+
+/// A function with all of its arguments bound which has not yet been called.
+/// 
+/// Usually constructed with the `defer` method of the `CurryN` types which passes every argument
+/// to the wrapped function; a partial application is not deferred as its wrapped function may be
+/// another `CurryN` which would only return a further partial application.
+/// 
+/// ```rust
+/// use curry::*;
+/// 
+/// let f: Thunk<_, (i32, i32, i32,)> = Curry3(|a, b, c| a + b + c).defer(1, 2, 3);
+/// assert_eq!(f.args(), &(1, 2, 3,));
+/// assert_eq!(f(), 6);
+/// assert_eq!(f.force(), 6);
+/// ```
+#[derive(PartialEq, Eq, Hash, Clone, Copy, Default, Debug,)]
+pub struct Thunk<F, Args,>
+  where F: ?Sized, {
+  /// The stored arguments.
+  pub args: Args,
+  /// The function being wrapped.
+  pub func: F,
+}
+
+impl<F, Args,> Thunk<F, Args,> {
+  /// Constructs a new `Thunk` from `args` and `func`.
+  #[inline]
+  pub const fn new(args: Args, func: F,) -> Self { Thunk { args, func, } }
+  /// References the stored arguments.
+  #[inline]
+  pub const fn args(&self,) -> &Args { &self.args }
+  /// Calls the function with the stored arguments.
+  #[inline]
+  pub fn force(self,) -> <Self as FnOnce<()>>::Output
+    where Self: FnOnce<()>, { self() }
+}
+
+/// Implements the `Fn*` traits for `Thunk` with a single arity of arguments.
+macro_rules! impl_thunk {
+  ($($arg_tp:ident $arg:ident,)*) => {
+    impl<$($arg_tp,)* F,> FnOnce<()> for Thunk<F, ($($arg_tp,)*),>
+      where F: FnOnce<($($arg_tp,)*)>, {
+      type Output = F::Output;
+
+      #[inline]
+      extern "rust-call" fn call_once(self, (): (),) -> Self::Output { self.func.call_once(self.args,) }
+    }
+
+    impl<$($arg_tp,)* F,> FnMut<()> for Thunk<F, ($($arg_tp,)*),>
+      where $($arg_tp: Clone,)*
+        F: FnMut<($($arg_tp,)*)>, {
+      extern "rust-call" fn call_mut(&mut self, (): (),) -> Self::Output { self.func.call_mut(self.args.clone(),) }
+    }
+
+    impl<$($arg_tp,)* F,> Fn<()> for Thunk<F, ($($arg_tp,)*),>
+      where $($arg_tp: Clone,)*
+        F: Fn<($($arg_tp,)*)>, {
+      extern "rust-call" fn call(&self, (): (),) -> Self::Output { self.func.call(self.args.clone(),) }
+    }
+  };
+}
+
+impl_thunk!();
+impl_thunk!(A a,);
+impl_thunk!(A a, B b,);
+impl_thunk!(A a, B b, C c,);
+impl_thunk!(A a, B b, C c, D d,);
+impl_thunk!(A a, B b, C c, D d, E e,);
+impl_thunk!(A a, B b, C c, D d, E e, G g,);
+
+impl<Args, T, U,> CoerceUnsized<Thunk<U, Args,>> for Thunk<T, Args,>
+  where T: CoerceUnsized<U>,
+    U: ?Sized, {}
+
+#[allow(unused,)]
+fn _assert_coerce_unsized(a: Thunk<&i32, (),>,) {
+  let _: Thunk<&dyn Send, (),> = a;
+}
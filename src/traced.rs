@@ -0,0 +1,285 @@
+//! Author --- DMorgan  
+//! Last Moddified --- 2026-10-19
+
+use core::{fmt, sync::atomic::{AtomicUsize, Ordering,}, time::Duration,};
+
+/// Receives the events of a [`Traced`] function.
+pub trait Tracer {
+  /// Called when `args` are partially applied leaving `remaining` arguments.
+  fn partial(&self, label: &str, args: &[&dyn fmt::Debug], remaining: usize,);
+  /// Called before the function is invoked with its final `args`.
+  fn call(&self, label: &str, args: &[&dyn fmt::Debug],);
+  /// Called with the result of the function and, with the `std` feature, the time the call took.
+  fn result(&self, label: &str, result: &dyn fmt::Debug, elapsed: Option<Duration>,);
+}
+
+impl<T,> Tracer for &'_ T
+  where T: Tracer + ?Sized, {
+  #[inline]
+  fn partial(&self, label: &str, args: &[&dyn fmt::Debug], remaining: usize,) { (**self).partial(label, args, remaining,) }
+  #[inline]
+  fn call(&self, label: &str, args: &[&dyn fmt::Debug],) { (**self).call(label, args,) }
+  #[inline]
+  fn result(&self, label: &str, result: &dyn fmt::Debug, elapsed: Option<Duration>,) { (**self).result(label, result, elapsed,) }
+}
+
+/// A [`Tracer`] which counts the events it receives.
+/// 
+/// ```rust
+/// use curry::*;
+/// 
+/// let tracer = CountingTracer::new();
+/// let f = Curry3(|a: i32, b: i32, c: i32| a + b + c).traced_with("sum", &tracer,);
+/// let g = f(1);
+/// assert_eq!(g(2)(3), 6);
+/// assert_eq!(f(4, 5)(6), 15);
+/// assert_eq!(tracer.partials(), 3);
+/// assert_eq!(tracer.calls(), 2);
+/// ```
+#[derive(Default, Debug,)]
+pub struct CountingTracer {
+  /// The number of partial applications.
+  partials: AtomicUsize,
+  /// The number of invocations.
+  calls: AtomicUsize,
+}
+
+impl CountingTracer {
+  /// Constructs a new `CountingTracer` with zeroed counts.
+  #[inline]
+  pub const fn new() -> Self { CountingTracer { partials: AtomicUsize::new(0,), calls: AtomicUsize::new(0,), } }
+  /// Returns the `CountingTracer` shared by the whole program.
+  #[inline]
+  pub fn global() -> &'static Self {
+    static GLOBAL: CountingTracer = CountingTracer::new();
+
+    &GLOBAL
+  }
+  /// Returns the number of partial applications traced.
+  #[inline]
+  pub fn partials(&self,) -> usize { self.partials.load(Ordering::Relaxed,) }
+  /// Returns the number of invocations traced.
+  #[inline]
+  pub fn calls(&self,) -> usize { self.calls.load(Ordering::Relaxed,) }
+}
+
+impl Tracer for CountingTracer {
+  #[inline]
+  fn partial(&self, _: &str, _: &[&dyn fmt::Debug], _: usize,) { self.partials.fetch_add(1, Ordering::Relaxed,); }
+  #[inline]
+  fn call(&self, _: &str, _: &[&dyn fmt::Debug],) { self.calls.fetch_add(1, Ordering::Relaxed,); }
+  #[inline]
+  fn result(&self, _: &str, _: &dyn fmt::Debug, _: Option<Duration>,) {}
+}
+
+/// A [`Tracer`] which prints every event to stderr.
+#[cfg(feature = "std",)]
+#[derive(PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Copy, Default, Debug,)]
+pub struct StderrTracer;
+
+#[cfg(feature = "std",)]
+impl Tracer for StderrTracer {
+  fn partial(&self, label: &str, args: &[&dyn fmt::Debug], remaining: usize,) {
+    std::eprintln!("[{}] applied {:?}, {} remaining", label, DebugArgs(args,), remaining,);
+  }
+  fn call(&self, label: &str, args: &[&dyn fmt::Debug],) {
+    std::eprintln!("[{}] called with {:?}", label, DebugArgs(args,),);
+  }
+  fn result(&self, label: &str, result: &dyn fmt::Debug, elapsed: Option<Duration>,) {
+    match elapsed {
+      Some(elapsed,) => std::eprintln!("[{}] returned {:?} in {:?}", label, result, elapsed,),
+      None => std::eprintln!("[{}] returned {:?}", label, result,),
+    }
+  }
+}
+
+/// The tracer used by the `traced` methods of the curried function types.
+/// 
+/// With the `std` feature events are printed by [`StderrTracer`], otherwise they are counted by
+/// [`CountingTracer::global`].
+#[cfg(feature = "std",)]
+pub type DefaultTracer = StderrTracer;
+/// The tracer used by the `traced` methods of the curried function types.
+/// 
+/// With the `std` feature events are printed by [`StderrTracer`], otherwise they are counted by
+/// [`CountingTracer::global`].
+#[cfg(not(feature = "std",),)]
+pub type DefaultTracer = &'static CountingTracer;
+
+/// Returns the [`DefaultTracer`].
+#[inline]
+pub(crate) fn default_tracer() -> DefaultTracer {
+  #[cfg(feature = "std",)]
+  { StderrTracer }
+  #[cfg(not(feature = "std",),)]
+  { CountingTracer::global() }
+}
+
+/// Formats arguments as a tuple.
+#[cfg(feature = "std",)]
+struct DebugArgs<'a,>(&'a [&'a dyn fmt::Debug],);
+
+#[cfg(feature = "std",)]
+impl fmt::Debug for DebugArgs<'_,> {
+  fn fmt(&self, fmt: &mut fmt::Formatter,) -> fmt::Result {
+    let mut tuple = fmt.debug_tuple("",);
+
+    for arg in self.0 { tuple.field(arg,); }
+    tuple.finish()
+  }
+}
+
+/// A curried function which reports its partial applications and invocations to a [`Tracer`].
+/// 
+/// `N` is the number of arguments remaining, each partial application returns a `Traced`
+/// wrapping the partially applied function. The arguments and result must be `Debug`.
+/// 
+/// Usually constructed with the `traced` or `traced_with` methods of the curried function types.
+/// 
+/// ```rust
+/// use curry::*;
+/// 
+/// let tracer = CountingTracer::new();
+/// let f = Curry2(|a: i32, b: i32| a * b).traced_with("mul", &tracer,);
+/// assert_eq!(f(6)(7), 42);
+/// assert_eq!(f(6, 7), 42);
+/// assert_eq!((tracer.partials(), tracer.calls(),), (1, 2,));
+/// ```
+#[derive(PartialEq, Eq, Clone, Copy, Default, Debug,)]
+pub struct Traced<F, T, const N: usize,>
+  where F: ?Sized, {
+  /// The label passed to the tracer.
+  pub label: &'static str,
+  /// The tracer receiving the events.
+  pub tracer: T,
+  /// The function being traced.
+  pub func: F,
+}
+
+impl<F, T, const N: usize,> Traced<F, T, N,> {
+  /// Constructs a new `Traced` from `func` reporting to `tracer` under `label`.
+  #[inline]
+  pub const fn new(label: &'static str, tracer: T, func: F,) -> Self { Traced { label, tracer, func, } }
+}
+
+/// Measures the time taken by `call` when the standard library is available.
+#[inline]
+fn timed<R,>(call: impl FnOnce() -> R,) -> (R, Option<Duration>,) {
+  #[cfg(feature = "std",)]
+  {
+    let start = std::time::Instant::now();
+    let res = call();
+
+    (res, Some(start.elapsed(),),)
+  }
+  #[cfg(not(feature = "std",),)]
+  { (call(), None,) }
+}
+
+/// Implements the `Fn*` traits for `Traced` for a single number of arguments.
+macro_rules! impl_traced {
+  (@partial $n:literal => $rest:literal $($arg_tp:ident $arg:ident,)+) => {
+    impl<$($arg_tp,)+ F, T,> FnOnce<($($arg_tp,)+)> for Traced<F, T, $n,>
+      where $($arg_tp: fmt::Debug,)+
+        F: FnOnce<($($arg_tp,)+)>,
+        T: Tracer, {
+      type Output = Traced<F::Output, T, $rest,>;
+
+      extern "rust-call" fn call_once(self, ($($arg,)+): ($($arg_tp,)+),) -> Self::Output {
+        self.tracer.partial(self.label, &[$(&$arg,)+], $rest,);
+        Traced::new(self.label, self.tracer, self.func.call_once(($($arg,)+),),)
+      }
+    }
+
+    impl<$($arg_tp,)+ F, T,> FnMut<($($arg_tp,)+)> for Traced<F, T, $n,>
+      where $($arg_tp: fmt::Debug,)+
+        F: FnMut<($($arg_tp,)+)>,
+        T: Tracer + Clone, {
+      extern "rust-call" fn call_mut(&mut self, ($($arg,)+): ($($arg_tp,)+),) -> Self::Output {
+        self.tracer.partial(self.label, &[$(&$arg,)+], $rest,);
+        Traced::new(self.label, self.tracer.clone(), self.func.call_mut(($($arg,)+),),)
+      }
+    }
+
+    impl<$($arg_tp,)+ F, T,> Fn<($($arg_tp,)+)> for Traced<F, T, $n,>
+      where $($arg_tp: fmt::Debug,)+
+        F: Fn<($($arg_tp,)+)>,
+        T: Tracer + Clone, {
+      extern "rust-call" fn call(&self, ($($arg,)+): ($($arg_tp,)+),) -> Self::Output {
+        self.tracer.partial(self.label, &[$(&$arg,)+], $rest,);
+        Traced::new(self.label, self.tracer.clone(), self.func.call(($($arg,)+),),)
+      }
+    }
+  };
+  (@call $n:literal => $($arg_tp:ident $arg:ident,)+) => {
+    impl<$($arg_tp,)+ F, T,> FnOnce<($($arg_tp,)+)> for Traced<F, T, $n,>
+      where $($arg_tp: fmt::Debug,)+
+        F: FnOnce<($($arg_tp,)+)>,
+        F::Output: fmt::Debug,
+        T: Tracer, {
+      type Output = F::Output;
+
+      extern "rust-call" fn call_once(self, ($($arg,)+): ($($arg_tp,)+),) -> Self::Output {
+        let Traced { label, tracer, func, } = self;
+
+        tracer.call(label, &[$(&$arg,)+],);
+        let (res, elapsed,) = timed(move || func.call_once(($($arg,)+),),);
+        tracer.result(label, &res, elapsed,);
+        res
+      }
+    }
+
+    impl<$($arg_tp,)+ F, T,> FnMut<($($arg_tp,)+)> for Traced<F, T, $n,>
+      where $($arg_tp: fmt::Debug,)+
+        F: FnMut<($($arg_tp,)+)>,
+        F::Output: fmt::Debug,
+        T: Tracer, {
+      extern "rust-call" fn call_mut(&mut self, ($($arg,)+): ($($arg_tp,)+),) -> Self::Output {
+        self.tracer.call(self.label, &[$(&$arg,)+],);
+        let (res, elapsed,) = timed(|| self.func.call_mut(($($arg,)+),),);
+        self.tracer.result(self.label, &res, elapsed,);
+        res
+      }
+    }
+
+    impl<$($arg_tp,)+ F, T,> Fn<($($arg_tp,)+)> for Traced<F, T, $n,>
+      where $($arg_tp: fmt::Debug,)+
+        F: Fn<($($arg_tp,)+)>,
+        F::Output: fmt::Debug,
+        T: Tracer, {
+      extern "rust-call" fn call(&self, ($($arg,)+): ($($arg_tp,)+),) -> Self::Output {
+        self.tracer.call(self.label, &[$(&$arg,)+],);
+        let (res, elapsed,) = timed(|| self.func.call(($($arg,)+),),);
+        self.tracer.result(self.label, &res, elapsed,);
+        res
+      }
+    }
+  };
+}
+
+impl_traced!(@call 1 => A a,);
+
+impl_traced!(@partial 2 => 1 A a,);
+impl_traced!(@call 2 => A a, B b,);
+
+impl_traced!(@partial 3 => 2 A a,);
+impl_traced!(@partial 3 => 1 A a, B b,);
+impl_traced!(@call 3 => A a, B b, C c,);
+
+impl_traced!(@partial 4 => 3 A a,);
+impl_traced!(@partial 4 => 2 A a, B b,);
+impl_traced!(@partial 4 => 1 A a, B b, C c,);
+impl_traced!(@call 4 => A a, B b, C c, D d,);
+
+impl_traced!(@partial 5 => 4 A a,);
+impl_traced!(@partial 5 => 3 A a, B b,);
+impl_traced!(@partial 5 => 2 A a, B b, C c,);
+impl_traced!(@partial 5 => 1 A a, B b, C c, D d,);
+impl_traced!(@call 5 => A a, B b, C c, D d, E e,);
+
+impl_traced!(@partial 6 => 5 A a,);
+impl_traced!(@partial 6 => 4 A a, B b,);
+impl_traced!(@partial 6 => 3 A a, B b, C c,);
+impl_traced!(@partial 6 => 2 A a, B b, C c, D d,);
+impl_traced!(@partial 6 => 1 A a, B b, C c, D d, E e,);
+impl_traced!(@call 6 => A a, B b, C c, D d, E e, G g,);
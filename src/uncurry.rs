@@ -1,5 +1,5 @@
 //! Author --- DMorgan  
-//! Last Moddified --- 2021-03-01
+//! Last Moddified --- 2026-10-19
 
 use core::ops::CoerceUnsized;
 
@@ -17,7 +17,7 @@ use core::ops::CoerceUnsized;
 /// assert_eq!(f(1)(2)(3)(4)(5)(6), 21);
 /// ```
 #[repr(transparent,)]
-#[derive(PartialEq, Eq, Clone, Copy, Default, Debug,)]
+#[derive(PartialEq, Eq, Hash, Clone, Copy, Default, Debug,)]
 pub struct Uncurry<F,>(pub F,)
   where F: ?Sized,;
 
@@ -169,7 +169,7 @@ impl<A, B, C, D, E, F, G,> Fn<(A, B, C, D, E, F,)> for Uncurry<G,>
 }
 
 impl<T, U,> CoerceUnsized<Uncurry<U,>> for Uncurry<T,>
-  where T: CoerceUnsized<U> + ?Sized,
+  where T: CoerceUnsized<U>,
     U: ?Sized, {}
 
 #[allow(unused,)]
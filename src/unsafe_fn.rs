@@ -0,0 +1,115 @@
+//! Author --- DMorgan  
+//! Last Moddified --- 2026-10-19
+
+/// A function pointer which may be `unsafe` to call.
+/// 
+/// Implemented for `fn`, `unsafe fn`, `extern "C" fn` and `unsafe extern "C" fn` pointers of up
+/// to 6 arguments.
+pub trait UnsafeFn<Args,> {
+  /// The returned type.
+  type Output;
+
+  /// Calls the function.
+  /// 
+  /// # Safety
+  /// 
+  /// The safety requirements of the function must be upheld.
+  unsafe fn call_unsafe(&self, args: Args,) -> Self::Output;
+}
+
+/// Implements `UnsafeFn` for the function pointers of a single arity.
+macro_rules! impl_unsafe_fn {
+  ($($arg_tp:ident $arg:ident,)*) => {
+    impl_unsafe_fn!(@impl (fn($($arg_tp,)*) -> R) $($arg_tp $arg,)*);
+    impl_unsafe_fn!(@impl (unsafe fn($($arg_tp,)*) -> R) $($arg_tp $arg,)*);
+    impl_unsafe_fn!(@impl (extern "C" fn($($arg_tp,)*) -> R) $($arg_tp $arg,)*);
+    impl_unsafe_fn!(@impl (unsafe extern "C" fn($($arg_tp,)*) -> R) $($arg_tp $arg,)*);
+  };
+  (@impl ($($fn_tp:tt)*) $($arg_tp:ident $arg:ident,)*) => {
+    impl<$($arg_tp,)* R,> UnsafeFn<($($arg_tp,)*)> for $($fn_tp)* {
+      type Output = R;
+
+      #[inline]
+      unsafe fn call_unsafe(&self, ($($arg,)*): ($($arg_tp,)*),) -> Self::Output { (self)($($arg,)*) }
+    }
+  };
+}
+
+impl_unsafe_fn!();
+impl_unsafe_fn!(A a,);
+impl_unsafe_fn!(A a, B b,);
+impl_unsafe_fn!(A a, B b, C c,);
+impl_unsafe_fn!(A a, B b, C c, D d,);
+impl_unsafe_fn!(A a, B b, C c, D d, E e,);
+impl_unsafe_fn!(A a, B b, C c, D d, E e, G g,);
+
+/// An [`UnsafeFn`] which has been asserted to be safe to call with any arguments.
+/// 
+/// Usually constructed with the `assume_safe` method of the curried function types.
+/// 
+/// ```rust
+/// use curry::*;
+/// 
+/// unsafe extern "C" fn sub(a: i32, b: i32,) -> i32 { a - b }
+/// 
+/// let f = Curry2(sub as unsafe extern "C" fn(i32, i32,) -> i32);
+/// assert_eq!(unsafe { f.call_unsafe(3, 1,) }, 2);
+/// 
+/// let f = unsafe { f.assume_safe() };
+/// assert_eq!(f(3)(1), 2);
+/// 
+/// // Curried function pointers hash by address.
+/// let mut set = std::collections::HashSet::new();
+/// set.insert(Curry2(sub as unsafe extern "C" fn(i32, i32,) -> i32));
+/// assert!(set.contains(&Curry2(sub as unsafe extern "C" fn(i32, i32,) -> i32)));
+/// ```
+#[repr(transparent,)]
+#[derive(PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Copy, Debug,)]
+pub struct AssumeSafe<F,>(F,)
+  where F: ?Sized,;
+
+impl<F,> AssumeSafe<F,> {
+  /// Constructs a new `AssumeSafe` from `f`.
+  /// 
+  /// # Safety
+  /// 
+  /// Calling `f` must be safe for every argument it accepts.
+  #[inline]
+  pub const unsafe fn new(f: F,) -> Self { AssumeSafe(f,) }
+  /// Returns the inner function.
+  #[inline]
+  pub fn into_inner(self,) -> F { self.0 }
+}
+
+/// Implements the `Fn*` traits for `AssumeSafe` for a single arity.
+macro_rules! impl_assume_safe {
+  ($($arg_tp:ident,)*) => {
+    impl<$($arg_tp,)* F,> FnOnce<($($arg_tp,)*)> for AssumeSafe<F,>
+      where F: UnsafeFn<($($arg_tp,)*)>, {
+      type Output = F::Output;
+
+      #[inline]
+      extern "rust-call" fn call_once(self, args: ($($arg_tp,)*),) -> Self::Output { unsafe { self.0.call_unsafe(args,) } }
+    }
+
+    impl<$($arg_tp,)* F,> FnMut<($($arg_tp,)*)> for AssumeSafe<F,>
+      where F: UnsafeFn<($($arg_tp,)*)>, {
+      #[inline]
+      extern "rust-call" fn call_mut(&mut self, args: ($($arg_tp,)*),) -> Self::Output { unsafe { self.0.call_unsafe(args,) } }
+    }
+
+    impl<$($arg_tp,)* F,> Fn<($($arg_tp,)*)> for AssumeSafe<F,>
+      where F: UnsafeFn<($($arg_tp,)*)>, {
+      #[inline]
+      extern "rust-call" fn call(&self, args: ($($arg_tp,)*),) -> Self::Output { unsafe { self.0.call_unsafe(args,) } }
+    }
+  };
+}
+
+impl_assume_safe!();
+impl_assume_safe!(A,);
+impl_assume_safe!(A, B,);
+impl_assume_safe!(A, B, C,);
+impl_assume_safe!(A, B, C, D,);
+impl_assume_safe!(A, B, C, D, E,);
+impl_assume_safe!(A, B, C, D, E, G,);